@@ -0,0 +1,168 @@
+use crate::camera::Camera;
+use crate::tileset::Tileset;
+use crate::CELL_SIZE;
+use quicksilver::geom::{Transform, Vector};
+use quicksilver::graphics::Background::Img;
+use quicksilver::lifecycle::Window;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Animation {
+    pub name: &'static str,
+    pub frames: usize,
+    pub frame_time: usize,
+    pub looping: bool,
+}
+
+pub const IDLE: Animation = Animation {
+    name: "idle",
+    frames: 1,
+    frame_time: 1,
+    looping: true,
+};
+pub const WALK: Animation = Animation {
+    name: "walk",
+    frames: 2,
+    frame_time: 8,
+    looping: true,
+};
+pub const MONSTER_IDLE: Animation = Animation {
+    name: "idle",
+    frames: 2,
+    frame_time: 20,
+    looping: true,
+};
+pub const ATTACK: Animation = Animation {
+    name: "attack",
+    frames: 2,
+    frame_time: 8,
+    looping: false,
+};
+pub const HURT: Animation = Animation {
+    name: "hurt",
+    frames: 6,
+    frame_time: 5,
+    looping: false,
+};
+pub const HIT_FLASH: Animation = Animation {
+    name: "hit",
+    frames: 4,
+    frame_time: 3,
+    looping: false,
+};
+pub const SPARKLE: Animation = Animation {
+    name: "sparkle",
+    frames: 4,
+    frame_time: 5,
+    looping: false,
+};
+pub const DEATH: Animation = Animation {
+    name: "death",
+    frames: 4,
+    frame_time: 6,
+    looping: false,
+};
+
+#[derive(Clone, Debug)]
+pub struct AnimationState {
+    pub animation: Animation,
+    fallback: Option<Animation>,
+    frame: usize,
+    timer: usize,
+    finished: bool,
+}
+impl AnimationState {
+    pub fn new(idle: Animation) -> Self {
+        Self {
+            animation: idle,
+            fallback: Some(idle),
+            frame: 0,
+            timer: 0,
+            finished: false,
+        }
+    }
+    pub fn once(animation: Animation) -> Self {
+        Self {
+            animation,
+            fallback: None,
+            frame: 0,
+            timer: 0,
+            finished: false,
+        }
+    }
+    pub fn stopped(animation: Animation) -> Self {
+        Self {
+            finished: true,
+            ..Self::once(animation)
+        }
+    }
+    pub fn play(&mut self, animation: Animation) {
+        if self.animation == animation && !self.finished && animation.looping {
+            return;
+        }
+        self.animation = animation;
+        self.frame = 0;
+        self.timer = 0;
+        self.finished = false;
+    }
+    pub fn update(&mut self) {
+        if self.finished {
+            return;
+        }
+        self.timer += 1;
+        if self.timer < self.animation.frame_time {
+            return;
+        }
+        self.timer = 0;
+        self.frame += 1;
+        if self.frame < self.animation.frames {
+            return;
+        }
+        if self.animation.looping {
+            self.frame = 0;
+        } else if let Some(fallback) = self.fallback {
+            self.play(fallback);
+        } else {
+            self.frame = self.animation.frames - 1;
+            self.finished = true;
+        }
+    }
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+    pub fn sprite_name(&self, prefix: &str) -> String {
+        if prefix.is_empty() {
+            format!("{}_{}", self.animation.name, self.frame)
+        } else {
+            format!("{}_{}_{}", prefix, self.animation.name, self.frame)
+        }
+    }
+}
+
+pub struct Effect {
+    pub location: Vector,
+    pub animation: AnimationState,
+}
+impl Effect {
+    pub fn new(location: Vector, animation: Animation) -> Self {
+        Self {
+            location,
+            animation: AnimationState::once(animation),
+        }
+    }
+    pub fn draw(&self, window: &mut Window, z: i32, camera: &Camera, tileset: &Tileset) {
+        if let Some(sprite) = tileset.get(&self.animation.sprite_name("")) {
+            window.draw_ex(
+                &camera.rectangle(
+                    self.location,
+                    Vector::new(CELL_SIZE as f32, CELL_SIZE as f32),
+                ),
+                Img(sprite),
+                Transform::IDENTITY,
+                z,
+            );
+        }
+    }
+}
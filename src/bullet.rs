@@ -1,46 +1,41 @@
-use crate::grid::dir::Dir;
+use crate::camera::Camera;
+use crate::config::Colors;
 use crate::grid::grid::Grid;
+use crate::gun::ShapeChoise;
 use crate::moveable::Moveable;
-use crate::player::Player;
-use crate::CELL_SIZE;
-use quicksilver::geom::Rectangle;
-use quicksilver::geom::Shape;
+use crate::polarity::Polarity;
+use crate::status::StatusKind;
+use crate::tileset::{bullet_name, Tileset};
 use quicksilver::geom::Transform;
 use quicksilver::geom::Vector;
-use quicksilver::graphics::Color;
 use quicksilver::lifecycle::Window;
-use quicksilver::prelude::Col;
+use quicksilver::prelude::{Blended, Col};
+
+const TRAIL_LENGTH: usize = 4;
 
 pub struct Bullet {
     pub location: Moveable,
     pub speed: f32,
-    pub pattern: Vec<Dir>,
+    pub pattern: Vec<Vector>,
     pub size: usize,
     pub damage: isize,
+    pub shape: ShapeChoise,
+    pub trail: Vec<Vector>,
+    pub affix: Option<StatusKind>,
 }
 impl Bullet {
     pub fn new_with_pattern(
         location: Vector,
         speed: f32,
-        dir: Dir,
+        aim: f32,
         pattern: Vec<i8>,
         damage: isize,
+        shape: ShapeChoise,
+        affix: Option<StatusKind>,
     ) -> Self {
-        let dir = u8::from(dir) as i8;
         let pattern = pattern
             .iter()
-            .map(|v| dir - v)
-            .map(|v| {
-                if v < 0 {
-                    v + 4
-                } else if v > 3 {
-                    v - 4
-                } else {
-                    v
-                }
-            })
-            .map(|v| v as u8)
-            .map(|v| v.into())
+            .map(|v| Vector::from_angle(aim + f32::from(*v) * 90.))
             .collect();
 
         Self {
@@ -49,36 +44,52 @@ impl Bullet {
             pattern,
             size: 20,
             damage,
+            shape,
+            trail: Vec::new(),
+            affix,
         }
     }
-    pub fn update(&mut self, grid: &Grid) -> bool {
-        for dir in &self.pattern {
-            let hit = self
-                .location
-                .move_some(*dir, self.speed, grid, self.size)
-                .is_some();
-            if hit {
-                return true;
+    pub fn polarity(&self) -> Polarity {
+        Polarity::of(self.damage)
+    }
+    pub fn update(&mut self, grid: &Grid) -> Option<Option<(usize, usize)>> {
+        self.trail.insert(0, self.location.location);
+        self.trail.truncate(TRAIL_LENGTH);
+        for step in &self.pattern {
+            if let Some(hit) = self.location.move_by(*step * self.speed, grid, self.size) {
+                return Some(hit.map(|(cell, _)| cell));
             }
         }
-        false
+        None
     }
-    pub fn draw(&self, window: &mut Window, z: i32, player: &Player) {
-        let color = if self.damage > 0 {
-            Color::BLUE
-        } else {
-            Color::CYAN
-        };
-        let screen_pos = player.grid_to_screen(&(
-            self.location.location.x / CELL_SIZE as f32,
-            self.location.location.y / CELL_SIZE as f32,
-        ));
-        window.draw_ex(
-            &Rectangle::new(screen_pos.clone(), (self.size as f32, self.size as f32))
-                .with_center(screen_pos),
-            Col(color),
-            Transform::IDENTITY,
-            z,
-        );
+    pub fn draw(
+        &self,
+        window: &mut Window,
+        z: i32,
+        camera: &Camera,
+        colors: &Colors,
+        tileset: &Tileset,
+    ) {
+        let color = self.polarity().color(colors);
+        let sprite = tileset.get(bullet_name(self.shape));
+        let positions = self
+            .trail
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(age, position)| (age + 1, *position))
+            .chain(std::iter::once((0, self.location.location)));
+        for (age, position) in positions {
+            let fade = 1. - age as f32 / (TRAIL_LENGTH + 1) as f32;
+            let size = self.size as f32 * fade;
+            let rect = camera.rectangle(position, Vector::new(size, size));
+            let color = color.with_alpha(fade);
+            match sprite {
+                Some(sprite) => {
+                    window.draw_ex(&rect, Blended(sprite, color), Transform::IDENTITY, z)
+                }
+                None => window.draw_ex(&rect, Col(color), Transform::IDENTITY, z),
+            }
+        }
     }
 }
@@ -0,0 +1,121 @@
+use crate::CELL_SIZE;
+use quicksilver::geom::Rectangle;
+use quicksilver::geom::Vector;
+use rand::Rng;
+
+const MIN_ZOOM: f32 = 0.5;
+const MAX_ZOOM: f32 = 2.5;
+const MAX_SHAKE_OFFSET: f32 = 12.;
+
+fn clamp_axis(position: f32, half_view: f32, world_size: f32) -> f32 {
+    if world_size <= half_view * 2. {
+        world_size / 2.
+    } else {
+        position.clamp(half_view, world_size - half_view)
+    }
+}
+
+pub struct Camera {
+    pub position: Vector,
+    pub viewport: Vector,
+    pub zoom: f32,
+    pub dead_zone: Vector,
+    pub smoothing: f32,
+    pub shake: f32,
+    shake_offset: Vector,
+}
+impl Camera {
+    pub fn new(position: Vector, viewport: Vector) -> Self {
+        Self {
+            position,
+            viewport,
+            zoom: 1.,
+            dead_zone: Vector::new(48, 32),
+            smoothing: 0.15,
+            shake: 0.,
+            shake_offset: Vector::ZERO,
+        }
+    }
+    pub fn snap_to(&mut self, target: Vector, world_size: Vector) {
+        self.position = target;
+        self.clamp_to(world_size);
+    }
+    pub fn follow(&mut self, target: Vector, world_size: Vector) {
+        let offset = target - self.position;
+        let mut desired = self.position;
+        if offset.x.abs() > self.dead_zone.x {
+            desired.x = target.x - self.dead_zone.x * offset.x.signum();
+        }
+        if offset.y.abs() > self.dead_zone.y {
+            desired.y = target.y - self.dead_zone.y * offset.y.signum();
+        }
+        self.position = self.position + (desired - self.position) * self.smoothing;
+        self.clamp_to(world_size);
+    }
+    fn clamp_to(&mut self, world_size: Vector) {
+        let half_view = self.viewport / (2. * self.zoom);
+        self.position = Vector::new(
+            clamp_axis(self.position.x, half_view.x, world_size.x),
+            clamp_axis(self.position.y, half_view.y, world_size.y),
+        );
+    }
+    pub fn add_shake(&mut self, amount: f32) {
+        self.shake = (self.shake + amount).min(1.);
+    }
+    pub fn update_shake(&mut self) {
+        if self.shake <= 0. {
+            self.shake_offset = Vector::ZERO;
+            return;
+        }
+        let mut rng = rand::thread_rng();
+        let strength = self.shake * self.shake * MAX_SHAKE_OFFSET;
+        self.shake_offset = Vector::new(
+            rng.gen_range(-strength, strength),
+            rng.gen_range(-strength, strength),
+        );
+        self.shake = (self.shake - 0.05).max(0.);
+    }
+    pub fn zoom_by(&mut self, amount: f32) {
+        self.zoom = (self.zoom + amount).clamp(MIN_ZOOM, MAX_ZOOM);
+    }
+    pub fn world_to_screen(&self, world: Vector) -> Vector {
+        (world - self.position) * self.zoom + self.viewport / 2 + self.shake_offset
+    }
+    pub fn screen_to_world(&self, screen: Vector) -> Vector {
+        (screen - self.shake_offset - self.viewport / 2) / self.zoom + self.position
+    }
+    pub fn cell_to_screen(&self, cell: (usize, usize)) -> Vector {
+        self.world_to_screen(Vector::new(
+            (cell.0 * CELL_SIZE) as f32,
+            (cell.1 * CELL_SIZE) as f32,
+        ))
+    }
+    pub fn scale(&self, size: f32) -> f32 {
+        size * self.zoom
+    }
+    pub fn rectangle(&self, world_center: Vector, world_size: Vector) -> Rectangle {
+        let size = world_size * self.zoom;
+        Rectangle::new(self.world_to_screen(world_center) - size / 2, size)
+    }
+    pub fn label_below(&self, rect: &Rectangle) -> Rectangle {
+        Rectangle::new(
+            (rect.pos.x, rect.pos.y + self.scale(20.)),
+            (self.scale(20.), self.scale(15.)),
+        )
+    }
+    pub fn visible_cells(&self) -> ((usize, usize), (usize, usize)) {
+        let cell_size = CELL_SIZE as f32;
+        let top_left = self.screen_to_world(Vector::ZERO) / cell_size;
+        let bottom_right = self.screen_to_world(self.viewport) / cell_size;
+        (
+            (
+                top_left.x.floor().max(0.) as usize,
+                top_left.y.floor().max(0.) as usize,
+            ),
+            (
+                bottom_right.x.ceil().max(0.) as usize,
+                bottom_right.y.ceil().max(0.) as usize,
+            ),
+        )
+    }
+}
@@ -0,0 +1,280 @@
+use crate::controls::{key_from_name, key_name, Control, Controls, ALL_CONTROLS};
+use quicksilver::graphics::Color;
+use quicksilver::graphics::FontStyle;
+use quicksilver::lifecycle::Window;
+use quicksilver::saving::{load_raw, save_raw};
+use quicksilver::Result;
+
+const APP_NAME: &str = "glitch_dungeon";
+const PROFILE: &str = "config";
+
+pub const RESOLUTIONS: [(u32, u32); 5] = [
+    (800, 600),
+    (1024, 768),
+    (1280, 720),
+    (1600, 900),
+    (1920, 1080),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Palette {
+    Default,
+    HighContrast,
+    Muted,
+}
+
+pub struct Colors {
+    pub floor: Color,
+    pub wall: Color,
+    pub start: Color,
+    pub end: Color,
+    pub gun: Color,
+    pub item: Color,
+    pub water: Color,
+    pub lava: Color,
+    pub pit: Color,
+    pub door: Color,
+    pub key: Color,
+    pub cracked: Color,
+    pub coins: Color,
+    pub spikes: Color,
+    pub sludge: Color,
+    pub teleporter: Color,
+    pub monster: Color,
+    pub player: Color,
+    pub player_hurt: Color,
+    pub bullet_positive: Color,
+    pub bullet_negative: Color,
+    pub damage: Color,
+    pub heal: Color,
+    pub blocked: Color,
+}
+
+impl Palette {
+    pub const ALL: [Palette; 3] = [Palette::Default, Palette::HighContrast, Palette::Muted];
+    pub fn name(self) -> &'static str {
+        match self {
+            Palette::Default => "default",
+            Palette::HighContrast => "high_contrast",
+            Palette::Muted => "muted",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Palette> {
+        Palette::ALL.iter().find(|v| v.name() == name).copied()
+    }
+    pub fn next(self) -> Palette {
+        let index = Palette::ALL.iter().position(|v| *v == self).unwrap_or(0);
+        Palette::ALL[(index + 1) % Palette::ALL.len()]
+    }
+    pub fn colors(self) -> Colors {
+        match self {
+            Palette::Default => Colors {
+                floor: Color::from_rgba(128, 64, 128, 1.),
+                wall: Color::BLACK,
+                start: Color::PURPLE,
+                end: Color::GREEN,
+                gun: Color::YELLOW,
+                item: Color::from_rgba(200, 140, 70, 1.),
+                water: Color::from_rgba(40, 80, 170, 1.),
+                lava: Color::from_rgba(220, 70, 20, 1.),
+                pit: Color::from_rgba(15, 10, 20, 1.),
+                door: Color::from_rgba(130, 90, 40, 1.),
+                key: Color::from_rgba(240, 200, 60, 1.),
+                cracked: Color::from_rgba(40, 30, 45, 1.),
+                coins: Color::from_rgba(255, 215, 0, 1.),
+                spikes: Color::from_rgba(150, 150, 160, 1.),
+                sludge: Color::from_rgba(80, 110, 40, 1.),
+                teleporter: Color::from_rgba(60, 200, 220, 1.),
+                monster: Color::INDIGO,
+                player: Color::WHITE,
+                player_hurt: Color::ORANGE,
+                bullet_positive: Color::BLUE,
+                bullet_negative: Color::CYAN,
+                damage: Color::RED,
+                heal: Color::GREEN,
+                blocked: Color::from_rgba(180, 180, 180, 1.),
+            },
+            Palette::HighContrast => Colors {
+                floor: Color::from_rgba(90, 90, 90, 1.),
+                wall: Color::BLACK,
+                start: Color::WHITE,
+                end: Color::GREEN,
+                gun: Color::YELLOW,
+                item: Color::from_rgba(255, 128, 0, 1.),
+                water: Color::from_rgba(0, 160, 255, 1.),
+                lava: Color::RED,
+                pit: Color::from_rgba(40, 0, 40, 1.),
+                door: Color::from_rgba(200, 200, 0, 1.),
+                key: Color::from_rgba(240, 228, 66, 1.),
+                cracked: Color::from_rgba(60, 60, 60, 1.),
+                coins: Color::from_rgba(255, 255, 0, 1.),
+                spikes: Color::from_rgba(200, 200, 200, 1.),
+                sludge: Color::from_rgba(0, 158, 115, 1.),
+                teleporter: Color::from_rgba(204, 121, 167, 1.),
+                monster: Color::RED,
+                player: Color::WHITE,
+                player_hurt: Color::MAGENTA,
+                bullet_positive: Color::from_rgba(0, 114, 178, 1.),
+                bullet_negative: Color::from_rgba(230, 159, 0, 1.),
+                damage: Color::from_rgba(213, 94, 0, 1.),
+                heal: Color::from_rgba(86, 180, 233, 1.),
+                blocked: Color::WHITE,
+            },
+            Palette::Muted => Colors {
+                floor: Color::from_rgba(70, 60, 80, 1.),
+                wall: Color::from_rgba(20, 18, 24, 1.),
+                start: Color::from_rgba(110, 90, 140, 1.),
+                end: Color::from_rgba(90, 140, 100, 1.),
+                gun: Color::from_rgba(190, 170, 90, 1.),
+                item: Color::from_rgba(160, 120, 90, 1.),
+                water: Color::from_rgba(60, 80, 120, 1.),
+                lava: Color::from_rgba(160, 80, 60, 1.),
+                pit: Color::from_rgba(25, 22, 30, 1.),
+                door: Color::from_rgba(110, 90, 70, 1.),
+                key: Color::from_rgba(190, 170, 110, 1.),
+                cracked: Color::from_rgba(35, 32, 40, 1.),
+                coins: Color::from_rgba(200, 180, 100, 1.),
+                spikes: Color::from_rgba(120, 120, 125, 1.),
+                sludge: Color::from_rgba(80, 95, 60, 1.),
+                teleporter: Color::from_rgba(90, 150, 160, 1.),
+                monster: Color::from_rgba(80, 70, 150, 1.),
+                player: Color::from_rgba(220, 220, 210, 1.),
+                player_hurt: Color::from_rgba(200, 130, 80, 1.),
+                bullet_positive: Color::from_rgba(80, 110, 180, 1.),
+                bullet_negative: Color::from_rgba(100, 180, 180, 1.),
+                damage: Color::from_rgba(190, 90, 80, 1.),
+                heal: Color::from_rgba(110, 170, 110, 1.),
+                blocked: Color::from_rgba(150, 150, 150, 1.),
+            },
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Config {
+    pub width: u32,
+    pub height: u32,
+    pub fullscreen: bool,
+    pub font_scale: f32,
+    pub palette: Palette,
+    pub glitch_intensity: f32,
+    pub controls: Controls,
+}
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            width: 800,
+            height: 600,
+            fullscreen: false,
+            font_scale: 1.,
+            palette: Palette::Default,
+            glitch_intensity: 1.,
+            controls: Controls::default(),
+        }
+    }
+}
+impl Config {
+    pub fn load() -> Self {
+        load_raw(APP_NAME, PROFILE)
+            .ok()
+            .and_then(|v| String::from_utf8(v).ok())
+            .map(|v| Config::parse(&v))
+            .unwrap_or_default()
+    }
+    pub fn save(&self) -> Result<()> {
+        save_raw(APP_NAME, PROFILE, self.to_text().as_bytes())?;
+        Ok(())
+    }
+    pub fn parse(text: &str) -> Self {
+        let mut config = Config::default();
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.splitn(2, '=');
+            let (key, value) = match (parts.next(), parts.next()) {
+                (Some(key), Some(value)) => (key.trim(), value.trim()),
+                _ => continue,
+            };
+            match key {
+                "width" => {
+                    if let Ok(width) = value.parse::<u32>() {
+                        config.width = width.max(320);
+                    }
+                }
+                "height" => {
+                    if let Ok(height) = value.parse::<u32>() {
+                        config.height = height.max(240);
+                    }
+                }
+                "fullscreen" => {
+                    if let Ok(fullscreen) = value.parse() {
+                        config.fullscreen = fullscreen;
+                    }
+                }
+                "font_scale" => {
+                    if let Ok(scale) = value.parse::<f32>() {
+                        config.font_scale = scale.clamp(0.5, 2.);
+                    }
+                }
+                "glitch_intensity" => {
+                    if let Ok(intensity) = value.parse::<f32>() {
+                        config.glitch_intensity = intensity.clamp(0., 1.);
+                    }
+                }
+                "palette" => {
+                    if let Some(palette) = Palette::from_name(value) {
+                        config.palette = palette;
+                    }
+                }
+                _ => {
+                    let control = key.strip_prefix("bind.").and_then(Control::from_name);
+                    if let Some(control) = control {
+                        let keys: Vec<_> = value
+                            .split(',')
+                            .filter_map(|v| key_from_name(v.trim()))
+                            .collect();
+                        if !keys.is_empty() {
+                            config.controls.keys.insert(control, keys);
+                        }
+                    }
+                }
+            }
+        }
+        config
+    }
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "width = {}\nheight = {}\nfullscreen = {}\nfont_scale = {}\npalette = {}\nglitch_intensity = {}\n",
+            self.width,
+            self.height,
+            self.fullscreen,
+            self.font_scale,
+            self.palette.name(),
+            self.glitch_intensity
+        );
+        for control in ALL_CONTROLS.iter() {
+            let keys: Vec<_> = self
+                .controls
+                .keys
+                .get(control)
+                .map(|keys| keys.iter().map(|v| key_name(*v)).collect())
+                .unwrap_or_default();
+            text.push_str(&format!("bind.{} = {}\n", control.name(), keys.join(", ")));
+        }
+        text
+    }
+    pub fn apply(&self, window: &mut Window) {
+        window.set_fullscreen(self.fullscreen);
+        if !self.fullscreen {
+            window.set_size((self.width, self.height));
+        }
+    }
+    pub fn font_style(&self) -> FontStyle {
+        FontStyle::new(100.0 * self.font_scale, Color::WHITE)
+    }
+    pub fn colors(&self) -> Colors {
+        self.palette.colors()
+    }
+}
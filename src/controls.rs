@@ -0,0 +1,299 @@
+use crate::player::{check_multiple, check_multiple_pressed};
+use quicksilver::geom::Vector;
+use quicksilver::input::ButtonState;
+use quicksilver::input::GamepadAxis;
+use quicksilver::input::GamepadButton;
+use quicksilver::input::Key;
+use quicksilver::lifecycle::Window;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Control {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    AimUp,
+    AimDown,
+    AimLeft,
+    AimRight,
+    PreviousGun,
+    NextGun,
+    Shoot,
+    ToggleAim,
+    Confirm,
+    Pause,
+    Settings,
+    ZoomIn,
+    ZoomOut,
+    Map,
+    TogglePolarity,
+    UseItem,
+}
+
+pub const ALL_CONTROLS: [Control; 20] = [
+    Control::MoveUp,
+    Control::MoveDown,
+    Control::MoveLeft,
+    Control::MoveRight,
+    Control::AimUp,
+    Control::AimDown,
+    Control::AimLeft,
+    Control::AimRight,
+    Control::PreviousGun,
+    Control::NextGun,
+    Control::Shoot,
+    Control::ToggleAim,
+    Control::Confirm,
+    Control::Pause,
+    Control::Settings,
+    Control::ZoomIn,
+    Control::ZoomOut,
+    Control::Map,
+    Control::TogglePolarity,
+    Control::UseItem,
+];
+
+pub const BINDABLE_KEYS: &[Key] = &[
+    Key::Key1,
+    Key::Key2,
+    Key::Key3,
+    Key::Key4,
+    Key::Key5,
+    Key::Key6,
+    Key::Key7,
+    Key::Key8,
+    Key::Key9,
+    Key::Key0,
+    Key::A,
+    Key::B,
+    Key::C,
+    Key::D,
+    Key::E,
+    Key::F,
+    Key::G,
+    Key::H,
+    Key::I,
+    Key::J,
+    Key::K,
+    Key::L,
+    Key::M,
+    Key::N,
+    Key::O,
+    Key::P,
+    Key::Q,
+    Key::R,
+    Key::S,
+    Key::T,
+    Key::U,
+    Key::V,
+    Key::W,
+    Key::X,
+    Key::Y,
+    Key::Z,
+    Key::Escape,
+    Key::Left,
+    Key::Up,
+    Key::Right,
+    Key::Down,
+    Key::Back,
+    Key::Return,
+    Key::Space,
+    Key::Tab,
+    Key::LShift,
+    Key::RShift,
+    Key::LControl,
+    Key::RControl,
+    Key::LAlt,
+    Key::RAlt,
+    Key::Comma,
+    Key::Period,
+    Key::Slash,
+    Key::Semicolon,
+    Key::Equals,
+    Key::Minus,
+];
+
+pub fn key_name(key: Key) -> String {
+    format!("{:?}", key)
+}
+
+pub fn key_from_name(name: &str) -> Option<Key> {
+    BINDABLE_KEYS
+        .iter()
+        .find(|key| key_name(**key) == name)
+        .copied()
+}
+
+impl Control {
+    pub fn name(self) -> &'static str {
+        match self {
+            Control::MoveUp => "move_up",
+            Control::MoveDown => "move_down",
+            Control::MoveLeft => "move_left",
+            Control::MoveRight => "move_right",
+            Control::AimUp => "aim_up",
+            Control::AimDown => "aim_down",
+            Control::AimLeft => "aim_left",
+            Control::AimRight => "aim_right",
+            Control::PreviousGun => "previous_gun",
+            Control::NextGun => "next_gun",
+            Control::Shoot => "shoot",
+            Control::ToggleAim => "toggle_aim",
+            Control::Confirm => "confirm",
+            Control::Pause => "pause",
+            Control::Settings => "settings",
+            Control::ZoomIn => "zoom_in",
+            Control::ZoomOut => "zoom_out",
+            Control::Map => "map",
+            Control::TogglePolarity => "toggle_polarity",
+            Control::UseItem => "use_item",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Control> {
+        ALL_CONTROLS.iter().find(|v| v.name() == name).copied()
+    }
+}
+
+#[derive(Clone)]
+pub struct Controls {
+    pub keys: HashMap<Control, Vec<Key>>,
+    pub buttons: HashMap<Control, Vec<GamepadButton>>,
+    pub dead_zone: f32,
+}
+impl Default for Controls {
+    fn default() -> Self {
+        let keys = vec![
+            (Control::MoveUp, vec![Key::W]),
+            (Control::MoveDown, vec![Key::S]),
+            (Control::MoveLeft, vec![Key::A]),
+            (Control::MoveRight, vec![Key::D]),
+            (Control::AimUp, vec![Key::Up]),
+            (Control::AimDown, vec![Key::Down]),
+            (Control::AimLeft, vec![Key::Left]),
+            (Control::AimRight, vec![Key::Right]),
+            (Control::PreviousGun, vec![Key::Q]),
+            (Control::NextGun, vec![Key::E]),
+            (Control::Shoot, vec![Key::F, Key::Space]),
+            (Control::ToggleAim, vec![Key::M]),
+            (Control::Confirm, vec![Key::Escape, Key::Return]),
+            (Control::Pause, vec![Key::Escape, Key::P]),
+            (Control::Settings, vec![Key::Tab]),
+            (Control::ZoomIn, vec![Key::Equals]),
+            (Control::ZoomOut, vec![Key::Minus]),
+            (Control::Map, vec![Key::N]),
+            (Control::TogglePolarity, vec![Key::R]),
+            (Control::UseItem, vec![Key::C]),
+        ];
+        let buttons = vec![
+            (Control::MoveUp, vec![GamepadButton::DpadUp]),
+            (Control::MoveDown, vec![GamepadButton::DpadDown]),
+            (Control::MoveLeft, vec![GamepadButton::DpadLeft]),
+            (Control::MoveRight, vec![GamepadButton::DpadRight]),
+            (Control::PreviousGun, vec![GamepadButton::ShoulderLeft]),
+            (Control::NextGun, vec![GamepadButton::ShoulderRight]),
+            (Control::Shoot, vec![GamepadButton::TriggerRight]),
+            (Control::ToggleAim, vec![GamepadButton::Select]),
+            (
+                Control::Confirm,
+                vec![GamepadButton::Start, GamepadButton::FaceDown],
+            ),
+            (Control::Pause, vec![GamepadButton::Start]),
+            (Control::Settings, vec![GamepadButton::FaceUp]),
+            (Control::ZoomIn, vec![GamepadButton::StickButtonRight]),
+            (Control::ZoomOut, vec![GamepadButton::StickButtonLeft]),
+            (Control::Map, vec![GamepadButton::FaceLeft]),
+            (Control::TogglePolarity, vec![GamepadButton::FaceRight]),
+            (Control::UseItem, vec![GamepadButton::TriggerLeft]),
+        ];
+        Self {
+            keys: keys.into_iter().collect(),
+            buttons: buttons.into_iter().collect(),
+            dead_zone: 0.25,
+        }
+    }
+}
+impl Controls {
+    fn check_buttons(
+        &self,
+        window: &Window,
+        control: Control,
+        check: impl Fn(ButtonState) -> bool,
+    ) -> bool {
+        match self.buttons.get(&control) {
+            Some(buttons) => window
+                .gamepads()
+                .iter()
+                .any(|pad| buttons.iter().any(|button| check(pad[*button]))),
+            None => false,
+        }
+    }
+    pub fn is_down(&self, window: &Window, control: Control) -> bool {
+        let keys = self.keys.get(&control).map(Vec::as_slice).unwrap_or(&[]);
+        check_multiple(window.keyboard(), keys)
+            || self.check_buttons(window, control, |v| v.is_down())
+    }
+    pub fn is_pressed(&self, window: &Window, control: Control) -> bool {
+        let keys = self.keys.get(&control).map(Vec::as_slice).unwrap_or(&[]);
+        check_multiple_pressed(window.keyboard(), keys)
+            || self.check_buttons(window, control, |v| v == ButtonState::Pressed)
+    }
+    fn stick(&self, window: &Window, x: GamepadAxis, y: GamepadAxis) -> Vector {
+        //gilrs reports up as positive while the browser reports it as negative
+        let flip = if cfg!(target_arch = "wasm32") {
+            1.
+        } else {
+            -1.
+        };
+        window
+            .gamepads()
+            .iter()
+            .map(|pad| Vector::new(pad[x], pad[y] * flip))
+            .find(|v| v.len() > self.dead_zone)
+            .unwrap_or(Vector::ZERO)
+    }
+    fn digital(&self, window: &Window, controls: [Control; 4]) -> Vector {
+        let mut res = Vector::ZERO;
+        if self.is_down(window, controls[0]) {
+            res.y -= 1.;
+        }
+        if self.is_down(window, controls[1]) {
+            res.y += 1.;
+        }
+        if self.is_down(window, controls[2]) {
+            res.x -= 1.;
+        }
+        if self.is_down(window, controls[3]) {
+            res.x += 1.;
+        }
+        res
+    }
+    pub fn movement(&self, window: &Window) -> Vector {
+        let stick = self.stick(window, GamepadAxis::LeftStickX, GamepadAxis::LeftStickY);
+        (self.digital(
+            window,
+            [
+                Control::MoveUp,
+                Control::MoveDown,
+                Control::MoveLeft,
+                Control::MoveRight,
+            ],
+        ) + stick)
+            .clamp((-1, -1), (1, 1))
+    }
+    pub fn aim(&self, window: &Window) -> Vector {
+        let stick = self.stick(window, GamepadAxis::RightStickX, GamepadAxis::RightStickY);
+        if stick != Vector::ZERO {
+            return stick;
+        }
+        self.digital(
+            window,
+            [
+                Control::AimUp,
+                Control::AimDown,
+                Control::AimLeft,
+                Control::AimRight,
+            ],
+        )
+    }
+}
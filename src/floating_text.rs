@@ -0,0 +1,90 @@
+use crate::camera::Camera;
+use crate::config::Colors;
+use crate::monster::DamageOutcome;
+use crate::text_cache::TextCache;
+use quicksilver::geom::{Rectangle, Transform, Vector};
+use quicksilver::graphics::{Background::Blended, Font, FontStyle};
+use quicksilver::lifecycle::Window;
+use quicksilver::Result;
+
+const LIFETIME: usize = 40;
+const RISE_SPEED: f32 = 0.8;
+const TEXT_HEIGHT: f32 = 14.;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FeedbackKind {
+    Damage,
+    Heal,
+    Blocked,
+}
+
+pub struct FloatingText {
+    pub location: Vector,
+    pub text: String,
+    pub kind: FeedbackKind,
+    age: usize,
+}
+impl FloatingText {
+    pub fn new(location: Vector, text: String, kind: FeedbackKind) -> Self {
+        Self {
+            location,
+            text,
+            kind,
+            age: 0,
+        }
+    }
+    pub fn from_outcome(location: Vector, outcome: DamageOutcome) -> Option<Self> {
+        match outcome {
+            DamageOutcome::Damaged(amount) => Some(Self::new(
+                location,
+                format!("-{}", amount.abs()),
+                FeedbackKind::Damage,
+            )),
+            DamageOutcome::Healed(amount) => Some(Self::new(
+                location,
+                format!("+{}", amount.abs()),
+                FeedbackKind::Heal,
+            )),
+            DamageOutcome::Blocked => Some(Self::new(
+                location,
+                String::from("blocked"),
+                FeedbackKind::Blocked,
+            )),
+            DamageOutcome::Cooldown => None,
+        }
+    }
+    pub fn update(&mut self) {
+        self.age += 1;
+        self.location.y -= RISE_SPEED;
+    }
+    pub fn is_done(&self) -> bool {
+        self.age >= LIFETIME
+    }
+    pub fn draw(
+        &self,
+        window: &mut Window,
+        z: i32,
+        camera: &Camera,
+        colors: &Colors,
+        text: (&TextCache, &Font, &FontStyle),
+    ) -> Result<()> {
+        let (cache, font, style) = text;
+        let image = cache.get(font, style, &self.text)?;
+        let size = image.area().size;
+        let height = camera.scale(TEXT_HEIGHT);
+        let size = Vector::new(height * size.x / size.y, height);
+        let color = match self.kind {
+            FeedbackKind::Damage => colors.damage,
+            FeedbackKind::Heal => colors.heal,
+            FeedbackKind::Blocked => colors.blocked,
+        };
+        let fade = 1. - self.age as f32 / LIFETIME as f32;
+        window.draw_ex(
+            &Rectangle::new(camera.world_to_screen(self.location) - size / 2, size),
+            Blended(&image, color.with_alpha(fade)),
+            Transform::IDENTITY,
+            z,
+        );
+        Ok(())
+    }
+}
@@ -1,16 +1,46 @@
+use crate::animation::{Effect, DEATH, SPARKLE};
 use crate::bullet::Bullet;
+use crate::camera::Camera;
+use crate::config::Config;
+use crate::controls::{key_name, Control, Controls};
+use crate::floating_text::{FeedbackKind, FloatingText};
+use crate::glitch::{draw_scrambled, Glitch};
 use crate::grid::Grid;
-use crate::monster::Monster;
+use crate::grid::Hazard;
+use crate::grid::Visibility;
+use crate::gun::Rarity;
+use crate::items::{ItemEffect, ItemRegistry};
+use crate::minimap::Minimap;
+use crate::monster::{DamageOutcome, Monster};
+use crate::player::Action;
 use crate::player::Player;
+use crate::polarity::Polarity;
+use crate::progression::{xp_for_level, XP_PER_KILL};
+use crate::status::StatusKind;
+use crate::text_cache::TextCache;
+use crate::tileset::Tileset;
 use crate::{CELL_SIZE, GRID_SIZE};
-use quicksilver::Result;
-
-use crate::player::Action;
-use quicksilver::geom::Vector;
+use quicksilver::geom::{Rectangle, Transform, Vector};
 use quicksilver::graphics::Font;
 use quicksilver::graphics::FontStyle;
+use quicksilver::graphics::{Background::Blended, Color};
 use quicksilver::lifecycle::Window;
+use quicksilver::Result;
 use rand::seq::SliceRandom;
+use rand::Rng;
+
+const SIGHT_RADIUS: usize = 8;
+const NEGATIVE_MONSTER_GLITCH: f32 = 0.2;
+const SWAP_GLITCH: f32 = 0.6;
+const HINT_TIME: usize = 600;
+const BOMB_RADIUS: usize = 3;
+const HUD_TEXT_HEIGHT: f32 = 20.;
+const POLARITY_HINTS: [&str; 2] = [
+    "Same polarity hurts, opposite heals up to 12",
+    "Flickering monsters are about to swap polarity",
+];
+const TEXT_CACHE_SIZE: usize = 128;
+const COIN_DROP_CHANCE: u32 = 70;
 
 pub struct GameState {
     pub grid: Grid,
@@ -18,47 +48,68 @@ pub struct GameState {
     pub monsters: Vec<Monster>,
     pub bullets: Vec<Bullet>,
     pub score: u64,
+    pub camera: Camera,
+    pub minimap: Minimap,
+    pub tileset: Tileset,
+    pub effects: Vec<Effect>,
+    pub glitch: Glitch,
+    pub text: TextCache,
+    pub floating_text: Vec<FloatingText>,
+    pub hint_timer: usize,
+    pub items: ItemRegistry,
+    pub floor: usize,
 }
 impl GameState {
-    fn basic_setup(font: &Font, style: &FontStyle) -> Result<(Grid, Vec<Monster>)> {
-        let grid = Grid::new(GRID_SIZE, GRID_SIZE)?;
+    fn basic_setup(items: &ItemRegistry, floor: usize) -> Result<(Grid, Vec<Monster>)> {
+        let grid = Grid::new(GRID_SIZE, GRID_SIZE, items)?;
         let mut monsters = Vec::new();
         let mut rng = rand::thread_rng();
         let possible_spawns: Vec<_> = grid
-            .tiles
-            .iter()
-            .enumerate()
-            .filter(|(_, v)| v.can_move && !v.is_start)
-            .map(|(key, _)| Grid::calc_pos_from_index(key, grid.length, grid.height))
+            .reachable_from(grid.start)
+            .into_iter()
+            .filter(|v| *v != grid.start)
             .collect();
         let amount = possible_spawns.len() / 20;
         for _ in 0..amount {
             let chosen = possible_spawns.choose(&mut rng).unwrap();
             monsters.push(Monster::new(
                 Vector::new((chosen.0 * CELL_SIZE) as i32, (chosen.1 * CELL_SIZE) as i32),
-                font,
-                style,
-            )?);
+                floor,
+            ));
         }
         Ok((grid, monsters))
     }
 
-    pub fn new(font: &Font, style: &FontStyle) -> Result<Self> {
-        let (grid, monsters) = Self::basic_setup(font, style)?;
+    pub fn new(viewport: Vector) -> Result<Self> {
+        let items = ItemRegistry::load();
+        let (grid, monsters) = Self::basic_setup(&items, 1)?;
         let start = grid.start;
-        let mut player = Player::new(start, &font, &style)?;
-        player.invis_timer = 30;
+        let player = Player::new(start);
+        let camera = Camera::new(player.location.location, viewport);
+        let mut grid = grid;
+        grid.update_visibility(player.location.cell_loc, SIGHT_RADIUS);
         Ok(Self {
             grid,
             player,
             monsters,
             bullets: Vec::new(),
             score: 0,
+            camera,
+            minimap: Minimap::new(),
+            tileset: Tileset::load()?,
+            effects: Vec::new(),
+            glitch: Glitch::new(),
+            text: TextCache::new(TEXT_CACHE_SIZE),
+            floating_text: Vec::new(),
+            hint_timer: HINT_TIME,
+            items,
+            floor: 1,
         })
     }
 
-    pub fn reset(&mut self, font: &Font, style: &FontStyle) -> Result<()> {
-        let (grid, monsters) = Self::basic_setup(font, style)?;
+    pub fn reset(&mut self) -> Result<()> {
+        self.floor += 1;
+        let (grid, monsters) = Self::basic_setup(&self.items, self.floor)?;
         self.grid = grid;
         self.monsters = monsters;
 
@@ -68,44 +119,404 @@ impl GameState {
             (start.1 * CELL_SIZE) as i32,
         ));
 
-        self.player.invis_timer = 30;
+        self.camera
+            .snap_to(self.player.location.location, self.grid.world_size());
+        self.grid
+            .update_visibility(self.player.location.cell_loc, SIGHT_RADIUS);
+        self.player.keys = 0;
+        self.player.status.clear();
+        self.player.take_hit();
         self.bullets = Vec::new();
+        self.effects = Vec::new();
+        self.floating_text = Vec::new();
         Ok(())
     }
 
-    pub fn draw(&self, window: &mut Window) {
-        let (start, end) = self.player.get_outer_cell_points();
-        let part = self.grid.get_part(start, end);
-        let mut z = 0;
-        part.into_iter().for_each(|(loc2, tile)| {
-            tile.draw(loc2, window, z, &self.player);
-            z = z + 1;
-        });
+    pub fn draw(
+        &self,
+        window: &mut Window,
+        config: &Config,
+        font: &Font,
+        style: &FontStyle,
+    ) -> Result<()> {
+        let colors = &config.colors();
+        let intensity = config.glitch_intensity;
+        let glitch = self.glitch.current(intensity);
+        self.text.set_scale(config.font_scale);
+        self.grid.draw_area(
+            window,
+            0,
+            &self.camera,
+            colors,
+            &self.tileset,
+            self.camera.visible_cells(),
+        );
+        self.glitch
+            .draw_corruption(window, 1, &self.camera, &self.grid, intensity);
+        let mut z = 2;
+        self.effects
+            .iter()
+            .filter(|effect| {
+                let cell = (
+                    effect.location.x as usize / CELL_SIZE,
+                    effect.location.y as usize / CELL_SIZE,
+                );
+                self.grid.visibility_at(cell) == Visibility::Visible
+            })
+            .for_each(|effect| {
+                effect.draw(window, z, &self.camera, &self.tileset);
+                z = z + 1;
+            });
         self.bullets.iter().for_each(|bullet| {
-            bullet.draw(window, z, &self.player);
+            bullet.draw(window, z, &self.camera, colors, &self.tileset);
             z = z + 1;
         });
-        self.monsters.iter().for_each(|monster| {
-            monster.draw(window, z, &self.player);
+        for monster in &self.monsters {
+            if self.grid.visibility_at(monster.location.cell_loc) != Visibility::Visible {
+                continue;
+            }
+            let glitch = if monster.is_about_to_swap() {
+                glitch.max(SWAP_GLITCH * intensity)
+            } else if monster.polarity == Polarity::Negative {
+                glitch.max(NEGATIVE_MONSTER_GLITCH * intensity)
+            } else {
+                glitch
+            };
+            monster.draw(window, z, &self.camera, colors, &self.tileset, glitch);
+            let health = self.text.get(font, style, &monster.health.to_string())?;
+            draw_scrambled(
+                window,
+                &monster.health_label(&self.camera),
+                &health,
+                glitch,
+                z,
+            );
             z = z + 1;
-        });
-        self.player.draw(window, z);
+        }
+        self.player
+            .draw(window, z, &self.camera, colors, &self.tileset, glitch);
+        let health_text = if self.player.shield > 0 {
+            format!("{} [{}]", self.player.health, self.player.shield)
+        } else {
+            self.player.health.to_string()
+        };
+        let health = self.text.get(font, style, &health_text)?;
+        draw_scrambled(
+            window,
+            &self.player.health_label(&self.camera),
+            &health,
+            glitch,
+            z,
+        );
+        for floating in &self.floating_text {
+            floating.draw(
+                window,
+                z + 1,
+                &self.camera,
+                colors,
+                (&self.text, font, style),
+            )?;
+        }
+        self.glitch.draw_scanlines(window, z + 2, intensity);
+        Ok(())
+    }
+
+    pub fn draw_map(&self, window: &mut Window, config: &Config) {
+        self.minimap.draw(
+            window,
+            &self.grid,
+            &self.player,
+            &self.monsters,
+            &config.colors(),
+        );
     }
 
-    pub fn update(
-        &mut self,
+    pub fn draw_hud(
+        &self,
         window: &mut Window,
+        config: &Config,
         font: &Font,
         style: &FontStyle,
-    ) -> Result<StateAction> {
-        let (points, action) = self.player.update(window, &mut self.grid, &font, &style)?;
+    ) -> Result<()> {
+        let colors = config.colors();
+        let screen = window.screen_size();
+        let draw_line = |window: &mut Window, text: &str, pos: Vector, color| -> Result<()> {
+            let image = self.text.get(font, style, text)?;
+            let size = image.area().size;
+            let size = Vector::new(HUD_TEXT_HEIGHT * size.x / size.y, HUD_TEXT_HEIGHT);
+            window.draw_ex(
+                &Rectangle::new(pos, size),
+                Blended(&image, color),
+                Transform::IDENTITY,
+                0,
+            );
+            Ok(())
+        };
+        let gun = &self.player.guns[self.player.selected_gun];
+        let mut gun_text = format!("Gun: {} ({})", gun.name, gun.polarity().symbol());
+        if gun.rarity == Rarity::Rare {
+            gun_text.push_str(" [rare]");
+        }
+        if let Some(affix) = gun.affix {
+            gun_text.push_str(&format!(" <{}>", affix.name()));
+        }
+        if gun.can_toggle {
+            let key = config
+                .controls
+                .keys
+                .get(&Control::TogglePolarity)
+                .and_then(|keys| keys.first())
+                .map(|key| key_name(*key));
+            if let Some(key) = key {
+                gun_text.push_str(&format!(" {}: flip", key));
+            }
+        }
+        draw_line(
+            window,
+            &gun_text,
+            Vector::new(10., screen.y - HUD_TEXT_HEIGHT - 10.),
+            gun.polarity().color(&colors),
+        )?;
+        if !self.player.items.is_empty() {
+            let names: Vec<_> = self.player.items.iter().map(|v| v.name.as_str()).collect();
+            let mut item_text = format!("Items: {}", names.join(", "));
+            let key = config
+                .controls
+                .keys
+                .get(&Control::UseItem)
+                .and_then(|keys| keys.first())
+                .map(|key| key_name(*key));
+            if let Some(key) = key {
+                item_text.push_str(&format!(" {}: use", key));
+            }
+            draw_line(
+                window,
+                &item_text,
+                Vector::new(10., screen.y - HUD_TEXT_HEIGHT * 2. - 14.),
+                Color::WHITE,
+            )?;
+        }
+        let mut wallet = format!(
+            "Lv {} ({}/{})  Coins: {}",
+            self.player.level,
+            self.player.xp,
+            xp_for_level(self.player.level),
+            self.player.coins
+        );
+        if self.player.keys > 0 {
+            wallet.push_str(&format!("  Keys: {}", self.player.keys));
+        }
+        draw_line(
+            window,
+            &wallet,
+            Vector::new(10., screen.y - HUD_TEXT_HEIGHT * 3. - 18.),
+            colors.coins,
+        )?;
+        if !self.player.status.active.is_empty() {
+            let statuses: Vec<_> = self
+                .player
+                .status
+                .active
+                .iter()
+                .map(|v| {
+                    if v.stacks > 1 {
+                        format!(
+                            "{} x{} ({}s)",
+                            v.kind.name(),
+                            v.stacks,
+                            v.remaining / 60 + 1
+                        )
+                    } else {
+                        format!("{} ({}s)", v.kind.name(), v.remaining / 60 + 1)
+                    }
+                })
+                .collect();
+            draw_line(
+                window,
+                &statuses.join("  "),
+                Vector::new(10., screen.y - HUD_TEXT_HEIGHT * 4. - 22.),
+                colors.damage,
+            )?;
+        }
+        if self.hint_timer > 0 {
+            let alpha = (self.hint_timer as f32 / 60.).min(1.);
+            for (index, hint) in POLARITY_HINTS.iter().enumerate() {
+                draw_line(
+                    window,
+                    hint,
+                    Vector::new(10., 10. + index as f32 * (HUD_TEXT_HEIGHT + 4.)),
+                    Color::WHITE.with_alpha(alpha),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_player_hazard(&mut self, previous: (usize, usize)) {
+        match self.player.location.hazard(&self.grid, previous) {
+            Some(Hazard::Damage(amount)) if !self.player.is_invulnerable() => {
+                self.player.take_damage(amount);
+                self.floating_text.push(FloatingText::new(
+                    self.player.location.location,
+                    format!("-{}", amount),
+                    FeedbackKind::Damage,
+                ));
+                self.camera.add_shake(0.4);
+            }
+            Some(Hazard::Status(kind)) => {
+                self.player.status.apply(kind, kind.default_duration());
+                self.floating_text.push(FloatingText::new(
+                    self.player.location.location,
+                    String::from(kind.name()),
+                    FeedbackKind::Damage,
+                ));
+            }
+            Some(Hazard::Teleport(cell)) => {
+                self.effects
+                    .push(Effect::new(self.player.location.location, SPARKLE));
+                self.player.location.teleport(cell);
+                self.effects
+                    .push(Effect::new(self.player.location.location, SPARKLE));
+                self.glitch.trigger(0.4);
+                self.camera
+                    .snap_to(self.player.location.location, self.grid.world_size());
+            }
+            _ => {}
+        }
+    }
+
+    fn apply_item(&mut self, effect: ItemEffect) {
+        let location = self.player.location.location;
+        let (text, kind) = match effect {
+            ItemEffect::Heal(amount) => (format!("+{}", amount), FeedbackKind::Heal),
+            ItemEffect::Shield(amount) => (format!("+{} shield", amount), FeedbackKind::Blocked),
+            ItemEffect::Speed { .. } => (String::from("speed up"), FeedbackKind::Heal),
+            ItemEffect::Bomb(damage) => {
+                self.camera.add_shake(0.8);
+                self.effects.push(Effect::new(location, DEATH));
+                let center = self.player.location.cell_loc;
+                for monster in &mut self.monsters {
+                    let cell = monster.location.cell_loc;
+                    if cell.0.abs_diff(center.0) > BOMB_RADIUS
+                        || cell.1.abs_diff(center.1) > BOMB_RADIUS
+                    {
+                        continue;
+                    }
+                    let outcome = monster.get_damage(monster.polarity.signed(damage));
+                    self.floating_text.extend(FloatingText::from_outcome(
+                        monster.location.location,
+                        outcome,
+                    ));
+                }
+                return;
+            }
+        };
+        self.floating_text
+            .push(FloatingText::new(location, text, kind));
+    }
+
+    pub fn update(&mut self, window: &mut Window, controls: &Controls) -> Result<StateAction> {
+        self.camera.viewport = window.screen_size();
+        self.hint_timer = self.hint_timer.saturating_sub(1);
+        if controls.is_pressed(window, Control::ZoomIn) {
+            self.camera.zoom_by(0.25);
+        }
+        if controls.is_pressed(window, Control::ZoomOut) {
+            self.camera.zoom_by(-0.25);
+        }
+        if controls.is_pressed(window, Control::Map) {
+            self.minimap.toggle();
+        }
+        let wheel = window.mouse().wheel().y;
+        if wheel != 0. {
+            self.camera.zoom_by(-wheel.signum() * 0.1);
+        }
+        let items_before = self.player.items.len();
+        let keys_before = self.player.keys;
+        let coins_before = self.player.coins;
+        let previous = self.player.location.cell_loc;
+        let (points, action) =
+            self.player
+                .update(window, controls, &self.camera, &mut self.grid)?;
+        if self.player.items.len() > items_before {
+            if let Some(item) = self.player.items.last() {
+                self.floating_text.push(FloatingText::new(
+                    self.player.location.location,
+                    item.name.clone(),
+                    FeedbackKind::Heal,
+                ));
+            }
+        }
+        if self.player.keys != keys_before {
+            let text = if self.player.keys > keys_before {
+                "key"
+            } else {
+                "unlocked"
+            };
+            self.floating_text.push(FloatingText::new(
+                self.player.location.location,
+                String::from(text),
+                FeedbackKind::Heal,
+            ));
+        }
+        if self.player.coins > coins_before {
+            self.floating_text.push(FloatingText::new(
+                self.player.location.location,
+                format!("+{} coins", self.player.coins - coins_before),
+                FeedbackKind::Heal,
+            ));
+        }
+        self.apply_player_hazard(previous);
+        let tick = self.player.status.update();
+        if tick > 0 {
+            let (text, kind) = match self.player.take_tick_damage(tick) {
+                0 => (String::from("blocked"), FeedbackKind::Blocked),
+                taken => (format!("-{}", taken), FeedbackKind::Damage),
+            };
+            self.floating_text
+                .push(FloatingText::new(self.player.location.location, text, kind));
+        }
+        if self.player.status.has(StatusKind::Corrupt) {
+            self.glitch.trigger(0.02);
+        }
+        if controls.is_pressed(window, Control::UseItem) {
+            if let Some(effect) = self.player.use_item() {
+                self.apply_item(effect);
+            }
+        }
+        self.grid
+            .update_visibility(self.player.location.cell_loc, SIGHT_RADIUS);
+        self.camera
+            .follow(self.player.location.location, self.grid.world_size());
+        self.camera.update_shake();
         self.score += points;
+        if points > 0 {
+            self.effects
+                .push(Effect::new(self.player.location.location, SPARKLE));
+            if self
+                .player
+                .guns
+                .last()
+                .is_some_and(|gun| gun.polarity() == Polarity::Negative)
+            {
+                self.glitch.trigger(0.5);
+            }
+        }
+        self.grid.update();
+        self.glitch.update(&self.grid);
+        self.effects.iter_mut().for_each(|v| v.animation.update());
+        self.floating_text.iter_mut().for_each(FloatingText::update);
+        self.floating_text.retain(|v| !v.is_done());
+        self.effects.retain(|v| !v.animation.is_finished());
         match action {
             Action::None => {}
             Action::NextScreen => return Ok(StateAction::NextLevel),
             Action::Shoot(gun) => {
-                let speed = gun.speed;
-                let damage = gun.damage;
+                let speed = gun.speed();
+                let damage = gun.damage();
+                let shape = gun.shape;
+                let affix = gun.affix;
+                let aim = self.player.shot_aim();
                 let bullets: Vec<_> = gun
                     .patterns
                     .into_iter()
@@ -113,9 +524,11 @@ impl GameState {
                         Bullet::new_with_pattern(
                             self.player.location.location,
                             speed,
-                            self.player.dir,
+                            aim,
                             v,
                             damage,
+                            shape,
+                            affix,
                         )
                     })
                     .collect();
@@ -123,32 +536,86 @@ impl GameState {
             }
         }
         let mut bullets = Vec::new();
+        let mut wall_hits = Vec::new();
         for mut bullet in self.bullets.drain(0..self.bullets.len()) {
-            if !bullet.update(&self.grid) {
-                bullets.push(bullet)
+            match bullet.update(&self.grid) {
+                None => bullets.push(bullet),
+                Some(Some(cell)) => wall_hits.push((cell, bullet.damage)),
+                Some(None) => {}
+            }
+        }
+        for (cell, damage) in wall_hits {
+            if self.grid.damage_wall(cell, damage) {
+                let location = Vector::new(
+                    ((cell.0 * CELL_SIZE) + CELL_SIZE / 2) as f32,
+                    ((cell.1 * CELL_SIZE) + CELL_SIZE / 2) as f32,
+                );
+                self.effects.push(Effect::new(location, DEATH));
+                self.camera.add_shake(0.5);
+                self.glitch.trigger(0.3);
             }
         }
         let bullets = bullets;
         let grid = &self.grid;
         let player = &self.player;
-        self.monsters
-            .iter_mut()
-            .for_each(|v| v.move_a_bit(&grid, player));
+        for monster in self.monsters.iter_mut() {
+            let previous = monster.location.cell_loc;
+            monster.move_a_bit(grid, player);
+            let tick = monster.status.update();
+            if tick > 0 {
+                let outcome = monster.take_tick_damage(tick);
+                self.floating_text.extend(FloatingText::from_outcome(
+                    monster.location.location,
+                    outcome,
+                ));
+            }
+            match monster.location.hazard(grid, previous) {
+                Some(Hazard::Damage(amount)) => {
+                    let outcome = monster.get_damage(monster.polarity.signed(amount));
+                    self.floating_text.extend(FloatingText::from_outcome(
+                        monster.location.location,
+                        outcome,
+                    ));
+                }
+                Some(Hazard::Teleport(cell)) => monster.location.teleport(cell),
+                Some(Hazard::Status(kind)) => monster.status.apply(kind, kind.default_duration()),
+                _ => {}
+            }
+        }
         let mut monsters = Vec::new();
         for mut monster in self.monsters.drain(0..self.monsters.len()) {
             for bullet in &bullets {
                 if bullet.location.cell_loc == monster.location.cell_loc {
-                    monster.get_damage(bullet.damage, &font, &style)?;
+                    let was_flashing = monster.is_flashing();
+                    let outcome = monster.get_damage(bullet.damage);
+                    if outcome == DamageOutcome::Blocked && was_flashing {
+                        continue;
+                    }
+                    if let (DamageOutcome::Damaged(_), Some(kind)) = (outcome, bullet.affix) {
+                        monster.status.apply(kind, kind.default_duration());
+                    }
+                    self.floating_text.extend(FloatingText::from_outcome(
+                        monster.location.location,
+                        outcome,
+                    ));
                 }
             }
             if monster.is_alive() {
                 if monster.location.cell_loc == self.player.location.cell_loc
-                    && self.player.invis_timer == 0
+                    && !self.player.is_invulnerable()
                 {
-                    self.player.health -= monster.damage;
-                    self.player.rendered_health =
-                        font.render(&self.player.health.to_string(), &style)?;
-                    self.player.invis_timer = 30;
+                    self.player.take_damage(monster.damage());
+                    if let Some(kind) = monster.attack_status {
+                        self.player.status.apply(kind, kind.default_duration());
+                    }
+                    self.floating_text.push(FloatingText::new(
+                        self.player.location.location,
+                        format!("-{}", monster.damage()),
+                        FeedbackKind::Damage,
+                    ));
+                    self.glitch.trigger(0.8);
+                    monster.attack();
+                    self.camera.add_shake(0.6);
                     if self.player.health <= 0 {
                         break;
                     }
@@ -156,23 +623,39 @@ impl GameState {
                 monsters.push(monster);
             } else {
                 self.score += 10;
+                if self.player.gain_xp(XP_PER_KILL) {
+                    self.floating_text.push(FloatingText::new(
+                        self.player.location.location,
+                        String::from("Level up!"),
+                        FeedbackKind::Heal,
+                    ));
+                }
+                let mut rng = rand::thread_rng();
+                if rng.gen_range(0, 100) < COIN_DROP_CHANCE {
+                    self.grid
+                        .drop_coins(monster.location.cell_loc, rng.gen_range(1, 4));
+                }
+                self.effects
+                    .push(Effect::new(monster.location.location, DEATH));
+                self.camera.add_shake(0.3);
             }
         }
         if self.player.health <= 0 {
             return Ok(StateAction::Die);
         } else {
-            if self.player.invis_timer > 0 {
-                self.player.invis_timer -= 1;
-            }
             self.monsters = monsters;
             self.bullets = bullets;
         }
+        if self.player.pending_levels > 0 {
+            return Ok(StateAction::LevelUp);
+        }
 
         Ok(StateAction::None)
     }
 }
 pub enum StateAction {
     NextLevel,
+    LevelUp,
     Die,
     None,
 }
@@ -0,0 +1,159 @@
+use crate::camera::Camera;
+use crate::grid::{Grid, Visibility};
+use crate::CELL_SIZE;
+use quicksilver::geom::{Rectangle, Shape, Transform, Vector};
+use quicksilver::graphics::{
+    Background::{Blended, Col, Img},
+    Color, Image,
+};
+use quicksilver::lifecycle::Window;
+use rand::Rng;
+
+const DECAY: f32 = 0.02;
+const MAX_SCANLINES: usize = 12;
+const MAX_CORRUPTED_TILES: usize = 24;
+const MAX_CHANNEL_OFFSET: f32 = 6.;
+const SCRAMBLE_SLICES: usize = 4;
+
+struct Scanline {
+    y: f32,
+    height: f32,
+    offset: f32,
+}
+
+pub struct Glitch {
+    pub strength: f32,
+    scanlines: Vec<Scanline>,
+    corrupted: Vec<((usize, usize), Color)>,
+}
+impl Glitch {
+    pub fn new() -> Self {
+        Self {
+            strength: 0.,
+            scanlines: Vec::new(),
+            corrupted: Vec::new(),
+        }
+    }
+    pub fn trigger(&mut self, amount: f32) {
+        self.strength = (self.strength + amount).min(1.);
+    }
+    pub fn update(&mut self, grid: &Grid) {
+        self.strength = (self.strength - DECAY).max(0.);
+        let mut rng = rand::thread_rng();
+        let scanlines = (MAX_SCANLINES as f32 * self.strength) as usize;
+        self.scanlines = (0..scanlines)
+            .map(|_| Scanline {
+                y: rng.gen_range(0., 1.),
+                height: rng.gen_range(2., 12.),
+                offset: rng.gen_range(-20., 20.),
+            })
+            .collect();
+        if rng.gen_range(0., 1.) < 0.2 {
+            let tiles = (MAX_CORRUPTED_TILES as f32 * self.strength) as usize;
+            self.corrupted = (0..tiles)
+                .map(|_| {
+                    let cell = (rng.gen_range(0, grid.length), rng.gen_range(0, grid.height));
+                    let color = Color::from_rgba(rng.gen(), rng.gen(), rng.gen(), 0.8);
+                    (cell, color)
+                })
+                .collect();
+        }
+    }
+    pub fn current(&self, intensity: f32) -> f32 {
+        self.strength * intensity
+    }
+    pub fn draw_corruption(
+        &self,
+        window: &mut Window,
+        z: i32,
+        camera: &Camera,
+        grid: &Grid,
+        intensity: f32,
+    ) {
+        let amount = (self.corrupted.len() as f32 * intensity) as usize;
+        let cell_size = camera.scale(CELL_SIZE as f32);
+        for (cell, color) in self.corrupted.iter().take(amount) {
+            if grid.visibility_at(*cell) == Visibility::Unseen {
+                continue;
+            }
+            window.draw_ex(
+                &Rectangle::new(camera.cell_to_screen(*cell), (cell_size, cell_size / 2.)),
+                Col(*color),
+                Transform::IDENTITY,
+                z,
+            );
+        }
+    }
+    pub fn draw_scanlines(&self, window: &mut Window, z: i32, intensity: f32) {
+        if intensity == 0. {
+            return;
+        }
+        let screen = window.screen_size();
+        for line in &self.scanlines {
+            window.draw_ex(
+                &Rectangle::new(
+                    (line.offset * intensity, line.y * screen.y),
+                    (screen.x, line.height),
+                ),
+                Col(Color::WHITE.with_alpha(0.15 * intensity)),
+                Transform::IDENTITY,
+                z,
+            );
+        }
+    }
+}
+
+pub fn draw_channel_split(
+    window: &mut Window,
+    rect: &Rectangle,
+    sprite: &Image,
+    amount: f32,
+    z: i32,
+) {
+    if amount <= 0. {
+        return;
+    }
+    let shift = Vector::new(amount * MAX_CHANNEL_OFFSET, 0.);
+    window.draw_ex(
+        &rect.translate(-shift),
+        Blended(sprite, Color::RED.with_alpha(0.5)),
+        Transform::IDENTITY,
+        z,
+    );
+    window.draw_ex(
+        &rect.translate(shift),
+        Blended(sprite, Color::CYAN.with_alpha(0.5)),
+        Transform::IDENTITY,
+        z,
+    );
+}
+
+pub fn draw_scrambled(window: &mut Window, rect: &Rectangle, text: &Image, amount: f32, z: i32) {
+    if amount <= 0. {
+        window.draw_ex(rect, Img(text), Transform::IDENTITY, z);
+        return;
+    }
+    let mut rng = rand::thread_rng();
+    let source = text.area();
+    let source_slice = source.size.y / SCRAMBLE_SLICES as f32;
+    let target_slice = rect.size.y / SCRAMBLE_SLICES as f32;
+    for slice in 0..SCRAMBLE_SLICES {
+        let part = text.subimage(Rectangle::new(
+            (0., source_slice * slice as f32),
+            (source.size.x, source_slice),
+        ));
+        let offset = rng.gen_range(-1., 1.) * amount * rect.size.x * 0.3;
+        window.draw_ex(
+            &Rectangle::new(
+                (
+                    rect.pos.x + offset,
+                    rect.pos.y + target_slice * slice as f32,
+                ),
+                (rect.size.x, target_slice),
+            ),
+            Img(&part),
+            Transform::IDENTITY,
+            z,
+        );
+    }
+}
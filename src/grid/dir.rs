@@ -34,3 +34,21 @@ impl From<Dir> for u8 {
         }
     }
 }
+impl Dir {
+    pub fn from_angle(angle: f32) -> Dir {
+        match angle.rem_euclid(360.) {
+            v if !(45. ..315.).contains(&v) => Dir::Right,
+            v if v < 135. => Dir::Down,
+            v if v < 225. => Dir::Left,
+            _ => Dir::Up,
+        }
+    }
+    pub fn angle(self) -> f32 {
+        match self {
+            Dir::Up => 270.,
+            Dir::Left => 180.,
+            Dir::Down => 90.,
+            Dir::Right => 0.,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::grid::Grid;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Visibility {
+    Unseen,
+    Seen,
+    Visible,
+}
+
+const OCTANTS: [[isize; 4]; 8] = [
+    [1, 0, 0, 1],
+    [0, 1, 1, 0],
+    [0, -1, 1, 0],
+    [-1, 0, 0, 1],
+    [-1, 0, 0, -1],
+    [0, -1, -1, 0],
+    [0, 1, -1, 0],
+    [1, 0, 0, -1],
+];
+
+struct Octant {
+    origin: (isize, isize),
+    radius: isize,
+    xx: isize,
+    xy: isize,
+    yx: isize,
+    yy: isize,
+}
+
+fn blocks_sight(grid: &Grid, cell: (isize, isize)) -> bool {
+    if cell.0 < 0 || cell.1 < 0 || cell.0 as usize >= grid.length || cell.1 as usize >= grid.height
+    {
+        return true;
+    }
+    grid.tiles[cell.1 as usize * grid.length + cell.0 as usize]
+        .terrain
+        .blocks_sight()
+}
+
+fn cast_light(
+    grid: &Grid,
+    octant: &Octant,
+    row: isize,
+    mut start: f32,
+    end: f32,
+    seen: &mut Vec<(usize, usize)>,
+) {
+    if start < end {
+        return;
+    }
+    let (origin, radius) = (octant.origin, octant.radius);
+    let radius_squared = radius * radius;
+    let mut new_start = 0.;
+    for j in row..=radius {
+        let dy = -j;
+        let mut blocked = false;
+        for dx in -j..=0 {
+            let cell = (
+                origin.0 + dx * octant.xx + dy * octant.xy,
+                origin.1 + dx * octant.yx + dy * octant.yy,
+            );
+            let left_slope = (dx as f32 - 0.5) / (dy as f32 + 0.5);
+            let right_slope = (dx as f32 + 0.5) / (dy as f32 - 0.5);
+            if start < right_slope {
+                continue;
+            } else if end > left_slope {
+                break;
+            }
+            let is_blocking = blocks_sight(grid, cell);
+            let in_grid = cell.0 >= 0
+                && cell.1 >= 0
+                && (cell.0 as usize) < grid.length
+                && (cell.1 as usize) < grid.height;
+            if dx * dx + dy * dy < radius_squared && in_grid {
+                seen.push((cell.0 as usize, cell.1 as usize));
+            }
+            if blocked {
+                if is_blocking {
+                    new_start = right_slope;
+                } else {
+                    blocked = false;
+                    start = new_start;
+                }
+            } else if is_blocking && j < radius {
+                blocked = true;
+                cast_light(grid, octant, j + 1, start, left_slope, seen);
+                new_start = right_slope;
+            }
+        }
+        if blocked {
+            break;
+        }
+    }
+}
+
+pub fn visible_cells(grid: &Grid, origin: (usize, usize), radius: usize) -> Vec<(usize, usize)> {
+    let mut seen = vec![origin];
+    let origin = (origin.0 as isize, origin.1 as isize);
+    for [xx, xy, yx, yy] in OCTANTS.iter().copied() {
+        let octant = Octant {
+            origin,
+            radius: radius as isize,
+            xx,
+            xy,
+            yx,
+            yy,
+        };
+        cast_light(grid, &octant, 1, 1., 0., &mut seen);
+    }
+    seen
+}
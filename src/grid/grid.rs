@@ -1,20 +1,52 @@
-use crate::grid::{Dir, Tile};
+use crate::camera::Camera;
+use crate::config::Colors;
+use crate::grid::fov::visible_cells;
+use crate::grid::hazard::spikes_raised;
+use crate::grid::{Dir, Terrain, Tile, TileContent, Visibility};
 use crate::gun::Gun;
-use quicksilver::graphics::Font;
-use quicksilver::graphics::FontStyle;
+use crate::items::{Item, ItemRegistry};
+use crate::tileset::{wall_name, Tileset};
+use crate::CELL_SIZE;
+use quicksilver::geom::Rectangle;
+use quicksilver::geom::Transform;
+use quicksilver::geom::Vector;
+use quicksilver::graphics::{
+    Background::{Blended, Col, Img},
+    Color, Drawable, Mesh,
+};
+use quicksilver::lifecycle::Window;
 use quicksilver::Result;
+use rand::seq::SliceRandom;
 use rand::Rng;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+const LOCKED_EXIT_CHANCE: u32 = 60;
+const SECRET_ROOMS: usize = 2;
+const CRACKED_HEALTH: isize = 30;
 
 pub struct Grid {
     pub tiles: Vec<Tile>,
     pub length: usize,
     pub height: usize,
     pub start: (usize, usize),
+    pub visibility: Vec<Visibility>,
+    pub teleporters: Vec<(usize, usize)>,
+    pub tick: usize,
+    pub revision: usize,
+    lit: Vec<usize>,
+}
+
+fn dim(color: Color) -> Color {
+    Color {
+        r: color.r * 0.35,
+        g: color.g * 0.35,
+        b: color.b * 0.35,
+        a: color.a,
+    }
 }
 
 impl Grid {
-    pub fn new(length: usize, height: usize) -> Result<Self> {
+    pub fn new(length: usize, height: usize, items: &ItemRegistry) -> Result<Self> {
         let mut grid = Vec::<Tile>::new();
         let amount = length * height;
         grid.reserve(amount);
@@ -69,37 +101,89 @@ impl Grid {
                 }
             }
         }
-        let mut iter = path.iter().enumerate().peekable();
+        let start_loc = Grid::calc_cell(&player_start, length, height);
+        let end_loc = Grid::calc_cell(&exit, length, height);
         let mut path2 = HashMap::new();
-        while let Some((key, v)) = iter.next() {
+        for v in &path {
             let loc = Grid::calc_cell(v, length, height);
-            let next = iter.peek();
-            let is_last = next.is_none();
-            let room = Tile {
-                is_start: key == 0,
-                is_end: is_last,
-                can_move: true,
-                has_gun: rng.gen_range(0, 100) < 2,
-            };
-            path2.insert(loc, room);
+            if loc == start_loc || loc == end_loc {
+                continue;
+            }
+            // The walk revisits cells; the first visit decides what the room holds.
+            path2.entry(loc).or_insert_with(|| {
+                let mut room = Tile::new(match rng.gen_range(0, 100) {
+                    0..=3 => Terrain::Water,
+                    4 => Terrain::Lava,
+                    5 => Terrain::Spikes,
+                    6 | 7 => Terrain::Sludge,
+                    _ => Terrain::Floor,
+                });
+                let roll = rng.gen_range(0, 100);
+                room.content = if roll < 2 {
+                    Some(TileContent::Gun(Tile::random_gun()))
+                } else if roll < 4 {
+                    items.random().map(TileContent::Item)
+                } else {
+                    None
+                };
+                room
+            });
         }
+        let mut start_room = Tile::new(Terrain::Floor);
+        start_room.is_start = true;
+        start_room.is_end = start_loc == end_loc;
+        path2.insert(start_loc, start_room);
+        if end_loc != start_loc {
+            let mut end_room = Tile::new(Terrain::Floor);
+            end_room.is_end = true;
+            path2.insert(end_loc, end_room);
+        }
+        let mut candidates: Vec<_> = path2
+            .iter()
+            .filter(|(key, v)| {
+                **key != start_loc
+                    && **key != end_loc
+                    && v.terrain == Terrain::Floor
+                    && v.content.is_none()
+            })
+            .map(|(key, _)| *key)
+            .collect();
+        candidates.sort_unstable();
+        let teleporters: Vec<_> = candidates
+            .choose_multiple(&mut rng, 2)
+            .map(|key| {
+                if let Some(tile) = path2.get_mut(key) {
+                    tile.terrain = Terrain::Teleporter;
+                }
+                Grid::calc_pos_from_index(*key, length, height)
+            })
+            .collect();
         for v in 0..length * height {
             grid.push(match path2.remove(&v) {
                 Some(x) => x,
-                None => Tile {
-                    is_start: false,
-                    is_end: false,
-                    can_move: false,
-                    has_gun: rng.gen_range(0, 100) < 2,
-                },
+                None => Tile::new(if rng.gen_range(0, 100) < 3 {
+                    Terrain::Pit
+                } else {
+                    Terrain::Wall
+                }),
             });
         }
-        Ok(Self {
+        let mut level = Self {
+            visibility: vec![Visibility::Unseen; grid.len()],
+            teleporters,
+            tick: 0,
+            revision: 0,
+            lit: Vec::new(),
             tiles: grid,
             length,
             height,
             start: player_start,
-        })
+        };
+        level.add_secret_rooms();
+        if rng.gen_range(0, 100) < LOCKED_EXIT_CHANCE {
+            level.lock_exit();
+        }
+        Ok(level)
     }
     fn calc_cell(point: &(usize, usize), length: usize, height: usize) -> usize {
         let mut x = point.0;
@@ -122,55 +206,352 @@ impl Grid {
         let res = ((key % length) as usize, (key / length) as usize);
         res
     }
-    pub fn get_part(
+    pub fn iter_area(
         &self,
         start: (usize, usize),
         end: (usize, usize),
-    ) -> Vec<((usize, usize), Tile)> {
-        self.tiles
-            .iter()
-            .enumerate()
-            .map(|(key, v)| {
-                let loc = Grid::calc_pos_from_index(key, self.length, self.height);
-                (loc, v)
-            })
-            .filter(|(loc, _)| {
-                loc.0 >= start.0 as usize
-                    && loc.0 <= end.0 as usize
-                    && loc.1 >= start.1 as usize
-                    && loc.1 <= end.1 as usize
+    ) -> impl Iterator<Item = ((usize, usize), &Tile)> {
+        let end_x = end.0.min(self.length.saturating_sub(1));
+        let end_y = end.1.min(self.height.saturating_sub(1));
+        let start_x = start.0;
+        (start.1..=end_y).flat_map(move |y| {
+            let row = &self.tiles[y * self.length..(y + 1) * self.length];
+            (start_x..=end_x).map(move |x| ((x, y), &row[x]))
+        })
+    }
+    pub fn draw_area(
+        &self,
+        window: &mut Window,
+        z: i32,
+        camera: &Camera,
+        colors: &Colors,
+        tileset: &Tileset,
+        (start, end): ((usize, usize), (usize, usize)),
+    ) {
+        let cell_size = camera.scale(CELL_SIZE as f32);
+        let mut layer = Mesh::new();
+        let mut run: Option<((usize, usize), usize, Color)> = None;
+        let draw_run = |layer: &mut Mesh, run: ((usize, usize), usize, Color)| {
+            let (first, amount, color) = run;
+            Rectangle::new(
+                camera.cell_to_screen(first),
+                (cell_size * amount as f32, cell_size),
+            )
+            .draw(layer, Col(color), Transform::IDENTITY, z);
+        };
+        for (loc, tile) in self.iter_area(start, end) {
+            let visibility = self.visibility_at(loc);
+            if visibility == Visibility::Unseen {
+                continue;
+            }
+            let sprite = match tile.sprite_name() {
+                Some("spikes_down") if spikes_raised(self, loc) => tileset.get("spikes_up"),
+                Some(name) => tileset.get(name),
+                None => tileset.get(&wall_name(self.open_sides(loc))),
+            };
+            let seen = visibility == Visibility::Seen;
+            if let Some(sprite) = sprite {
+                let cell = Rectangle::new(camera.cell_to_screen(loc), (cell_size, cell_size));
+                let background = if seen {
+                    Blended(sprite, dim(Color::WHITE))
+                } else {
+                    Img(sprite)
+                };
+                cell.draw(&mut layer, background, Transform::IDENTITY, z);
+                continue;
+            }
+            let color = if seen {
+                dim(tile.color(colors))
+            } else {
+                tile.color(colors)
+            };
+            run = match run {
+                Some((first, amount, run_color))
+                    if first.1 == loc.1 && first.0 + amount == loc.0 && run_color == color =>
+                {
+                    Some((first, amount + 1, run_color))
+                }
+                Some(finished) => {
+                    draw_run(&mut layer, finished);
+                    Some((loc, 1, color))
+                }
+                None => Some((loc, 1, color)),
+            };
+        }
+        if let Some(finished) = run {
+            draw_run(&mut layer, finished);
+        }
+        window.mesh().extend(&layer);
+    }
+    fn neighbours(&self, cell: (usize, usize)) -> Vec<(usize, usize)> {
+        let (x, y) = cell;
+        let mut res = Vec::new();
+        if x > 0 {
+            res.push((x - 1, y));
+        }
+        if y > 0 {
+            res.push((x, y - 1));
+        }
+        if x + 1 < self.length {
+            res.push((x + 1, y));
+        }
+        if y + 1 < self.height {
+            res.push((x, y + 1));
+        }
+        res
+    }
+    pub fn reachable_from(&self, start: (usize, usize)) -> Vec<(usize, usize)> {
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::new();
+        seen.insert(start);
+        queue.push_back(start);
+        while let Some(cell) = queue.pop_front() {
+            for next in self.neighbours(cell) {
+                if self.get_tile(next).is_some_and(|v| v.can_move()) && seen.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        let mut res: Vec<_> = seen.into_iter().collect();
+        res.sort_unstable();
+        res
+    }
+    fn set_terrain(&mut self, cell: (usize, usize), terrain: Terrain) {
+        let index = Grid::calc_cell_unbound(&cell, self.length, self.height);
+        self.tiles[index].terrain = terrain;
+        self.revision += 1;
+    }
+    fn is_sealed(&self, cell: (usize, usize), entrance: (usize, usize)) -> bool {
+        self.get_tile(cell).map(|v| v.terrain) == Some(Terrain::Wall)
+            && self
+                .neighbours(cell)
+                .into_iter()
+                .filter(|v| *v != entrance)
+                .all(|v| self.get_tile(v).map(|v| v.terrain) == Some(Terrain::Wall))
+    }
+    fn add_secret_rooms(&mut self) {
+        let mut candidates = Vec::new();
+        for (index, tile) in self.tiles.iter().enumerate() {
+            if tile.terrain != Terrain::Wall {
+                continue;
+            }
+            let wall = Grid::calc_pos_from_index(index, self.length, self.height);
+            for floor in self.neighbours(wall) {
+                if !self.get_tile(floor).is_some_and(|v| v.can_move()) {
+                    continue;
+                }
+                let room = (
+                    (2 * wall.0).checked_sub(floor.0),
+                    (2 * wall.1).checked_sub(floor.1),
+                );
+                if let (Some(x), Some(y)) = room {
+                    if self.is_sealed((x, y), wall) {
+                        candidates.push((wall, (x, y)));
+                    }
+                }
+            }
+        }
+        let mut rng = rand::thread_rng();
+        let mut used: Vec<(usize, usize)> = Vec::new();
+        candidates.shuffle(&mut rng);
+        for (wall, room) in candidates {
+            if used.len() >= SECRET_ROOMS * 2 {
+                break;
+            }
+            if used
+                .iter()
+                .any(|v| v.0.abs_diff(room.0) + v.1.abs_diff(room.1) < 3)
+            {
+                continue;
+            }
+            self.set_terrain(wall, Terrain::Cracked(CRACKED_HEALTH));
+            self.set_terrain(room, Terrain::Floor);
+            let index = Grid::calc_cell_unbound(&room, self.length, self.height);
+            self.tiles[index].content = Some(TileContent::Gun(Tile::rare_gun()));
+            used.push(wall);
+            used.push(room);
+        }
+    }
+    pub fn damage_wall(&mut self, cell: (usize, usize), amount: isize) -> bool {
+        let health = match self.get_tile(cell).map(|v| v.terrain) {
+            Some(Terrain::Cracked(health)) => health - amount.abs().max(1),
+            _ => return false,
+        };
+        if health > 0 {
+            self.set_terrain(cell, Terrain::Cracked(health));
+            false
+        } else {
+            self.set_terrain(cell, Terrain::Floor);
+            true
+        }
+    }
+    fn lock_exit(&mut self) {
+        let end = match self.tiles.iter().position(|v| v.is_end && !v.is_start) {
+            Some(index) => Grid::calc_pos_from_index(index, self.length, self.height),
+            None => return,
+        };
+        let doors: Vec<_> = self
+            .neighbours(end)
+            .into_iter()
+            .filter(|v| self.get_tile(*v).is_some_and(|v| v.can_move()))
+            .collect();
+        if doors.is_empty() || doors.contains(&self.start) {
+            return;
+        }
+        if self.teleporters.iter().any(|v| doors.contains(v)) {
+            // A pad next to the exit would skip the door, so the whole pair goes.
+            for pad in std::mem::take(&mut self.teleporters) {
+                self.set_terrain(pad, Terrain::Floor);
+            }
+        }
+        for door in &doors {
+            self.set_terrain(*door, Terrain::Door);
+        }
+        let candidates: Vec<_> = self
+            .reachable_from(self.start)
+            .into_iter()
+            .filter(|v| {
+                *v != self.start
+                    && self
+                        .get_tile(*v)
+                        .is_some_and(|v| v.terrain == Terrain::Floor && v.content.is_none())
             })
-            .map(|(loc, v)| (loc, v.clone()))
-            .collect()
+            .collect();
+        match candidates.choose(&mut rand::thread_rng()) {
+            Some(key) => {
+                let index = Grid::calc_cell_unbound(key, self.length, self.height);
+                self.tiles[index].content = Some(TileContent::Key);
+            }
+            None => {
+                for door in &doors {
+                    self.set_terrain(*door, Terrain::Floor);
+                }
+            }
+        }
+    }
+    pub fn open_door(&mut self, cell: (usize, usize)) -> bool {
+        if self.get_tile(cell).map(|v| v.terrain) != Some(Terrain::Door) {
+            return false;
+        }
+        self.set_terrain(cell, Terrain::Floor);
+        true
+    }
+    pub fn take_key(&mut self, cell: (usize, usize)) -> bool {
+        if !self.get_tile(cell).is_some_and(|v| v.has_key()) {
+            return false;
+        }
+        let index = Grid::calc_cell_unbound(&cell, self.length, self.height);
+        self.tiles[index].content = None;
+        self.revision += 1;
+        true
+    }
+    pub fn drop_coins(&mut self, cell: (usize, usize), amount: u64) -> bool {
+        match self.get_tile(cell) {
+            Some(tile) if tile.can_move() && tile.content.is_none() => {}
+            _ => return false,
+        }
+        let index = Grid::calc_cell_unbound(&cell, self.length, self.height);
+        self.tiles[index].content = Some(TileContent::Coins(amount));
+        self.revision += 1;
+        true
+    }
+    pub fn take_coins(&mut self, cell: (usize, usize)) -> Option<u64> {
+        let amount = match self.get_tile(cell)?.content {
+            Some(TileContent::Coins(amount)) => amount,
+            _ => return None,
+        };
+        let index = Grid::calc_cell_unbound(&cell, self.length, self.height);
+        self.tiles[index].content = None;
+        self.revision += 1;
+        Some(amount)
+    }
+    pub fn update(&mut self) {
+        self.tick = self.tick.wrapping_add(1);
+    }
+    pub fn open_sides(&self, cell: (usize, usize)) -> u8 {
+        let is_open = |x: Option<usize>, y: Option<usize>| match (x, y) {
+            (Some(x), Some(y)) => self.get_tile((x, y)).is_some_and(|v| v.can_move()),
+            _ => false,
+        };
+        let (x, y) = cell;
+        let mut sides = 0;
+        if is_open(Some(x), y.checked_sub(1)) {
+            sides |= 1;
+        }
+        if is_open(Some(x + 1), Some(y)) {
+            sides |= 2;
+        }
+        if is_open(Some(x), Some(y + 1)) {
+            sides |= 4;
+        }
+        if is_open(x.checked_sub(1), Some(y)) {
+            sides |= 8;
+        }
+        sides
+    }
+    pub fn visibility_at(&self, cell: (usize, usize)) -> Visibility {
+        if cell.0 >= self.length || cell.1 >= self.height {
+            return Visibility::Unseen;
+        }
+        self.visibility[Grid::calc_cell_unbound(&cell, self.length, self.height)]
+    }
+    pub fn update_visibility(&mut self, origin: (usize, usize), radius: usize) {
+        for index in self.lit.drain(..) {
+            self.visibility[index] = Visibility::Seen;
+        }
+        for cell in visible_cells(self, origin, radius) {
+            let index = Grid::calc_cell_unbound(&cell, self.length, self.height);
+            if self.visibility[index] == Visibility::Unseen {
+                self.revision += 1;
+            }
+            self.visibility[index] = Visibility::Visible;
+            self.lit.push(index);
+        }
     }
-    pub fn get_cell(&self, cell: (usize, usize)) -> Option<((usize, usize), Tile)> {
+    pub fn world_size(&self) -> Vector {
+        Vector::new(
+            (self.length * CELL_SIZE) as f32,
+            (self.height * CELL_SIZE) as f32,
+        )
+    }
+    pub fn get_tile(&self, cell: (usize, usize)) -> Option<&Tile> {
+        if cell.0 >= self.length || cell.1 >= self.height {
+            return None;
+        }
+        self.tiles
+            .get(Grid::calc_cell_unbound(&cell, self.length, self.height))
+    }
+    pub fn get_item(&mut self, cell: &(usize, usize)) -> Option<Item> {
         if cell.0 > self.length - 1 || cell.1 > self.height - 1 {
             return None;
         }
-        let index = Grid::calc_cell_unbound(&cell, self.length, self.height);
-        self.tiles.get(index).map(|v| {
-            (
-                Grid::calc_pos_from_index(index, self.length, self.height),
-                v.clone(),
-            )
-        })
+        let index = Grid::calc_cell_unbound(cell, self.length, self.height);
+        let tile = self.tiles.get_mut(index)?;
+        if !tile.has_item() {
+            return None;
+        }
+        self.revision += 1;
+        match tile.content.take() {
+            Some(TileContent::Item(item)) => Some(item),
+            _ => None,
+        }
     }
-    pub fn get_gun(
-        &mut self,
-        cell: &(usize, usize),
-        font: &Font,
-        style: &FontStyle,
-    ) -> Result<Option<Gun>> {
+    pub fn get_gun(&mut self, cell: &(usize, usize)) -> Option<Gun> {
         if cell.0 > self.length - 1 || cell.1 > self.height - 1 {
-            return Ok(None);
+            return None;
         }
         let index = Grid::calc_cell_unbound(&cell, self.length, self.height);
         if let Some(tile) = self.tiles.get_mut(index) {
-            let gun = tile.get_gun(font, style);
-            tile.has_gun = false;
-            gun
+            if !tile.has_gun() {
+                return None;
+            }
+            self.revision += 1;
+            match tile.content.take() {
+                Some(TileContent::Gun(gun)) => Some(gun),
+                _ => None,
+            }
         } else {
-            Ok(None)
+            None
         }
     }
 }
@@ -0,0 +1,46 @@
+use crate::grid::{Grid, Terrain};
+use crate::status::StatusKind;
+
+const SPIKE_PERIOD: usize = 90;
+const SPIKE_RAISED: usize = 30;
+const SPIKE_DAMAGE: isize = 8;
+const SLUDGE_SLOW: f32 = 0.4;
+const WATER_SLOW: f32 = 0.7;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Hazard {
+    Damage(isize),
+    Slow(f32),
+    Status(StatusKind),
+    Teleport((usize, usize)),
+}
+
+pub fn spikes_raised(grid: &Grid, cell: (usize, usize)) -> bool {
+    (grid.tick + cell.0 * 7 + cell.1 * 13) % SPIKE_PERIOD < SPIKE_RAISED
+}
+
+pub fn hazard_at(grid: &Grid, cell: (usize, usize), entered: bool) -> Option<Hazard> {
+    match grid.get_tile(cell)?.terrain {
+        Terrain::Spikes if spikes_raised(grid, cell) => Some(Hazard::Damage(SPIKE_DAMAGE)),
+        Terrain::Lava if entered => Some(Hazard::Status(StatusKind::Burn)),
+        Terrain::Sludge => Some(Hazard::Slow(SLUDGE_SLOW)),
+        Terrain::Water => Some(Hazard::Slow(WATER_SLOW)),
+        Terrain::Teleporter if entered => {
+            let index = grid.teleporters.iter().position(|v| *v == cell)?;
+            let target = grid.teleporters[(index + 1) % grid.teleporters.len()];
+            if target == cell {
+                None
+            } else {
+                Some(Hazard::Teleport(target))
+            }
+        }
+        _ => None,
+    }
+}
+
+pub fn speed_factor(grid: &Grid, cell: (usize, usize)) -> f32 {
+    match hazard_at(grid, cell, false) {
+        Some(Hazard::Slow(factor)) => factor,
+        _ => 1.,
+    }
+}
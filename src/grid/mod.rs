@@ -1,6 +1,10 @@
 pub mod dir;
+pub mod fov;
 pub mod grid;
+pub mod hazard;
 pub mod tile;
 pub use dir::Dir;
+pub use fov::Visibility;
 pub use grid::Grid;
-pub use tile::Tile;
+pub use hazard::Hazard;
+pub use tile::{Terrain, Tile, TileContent};
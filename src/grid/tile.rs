@@ -1,25 +1,107 @@
+use crate::config::Colors;
 use crate::gun::get_random_name;
-use crate::gun::Gun;
-use crate::player::Player;
-use quicksilver::geom::Rectangle;
-use quicksilver::geom::Transform;
-use quicksilver::graphics::{Background::Col, Color, Font, FontStyle};
-use quicksilver::lifecycle::Window;
-use quicksilver::Result;
+use crate::gun::{Gun, Rarity};
+use crate::items::Item;
+use crate::status::StatusKind;
+use quicksilver::graphics::Color;
+use rand::seq::SliceRandom;
 use rand::Rng;
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Terrain {
+    Floor,
+    Wall,
+    Water,
+    Lava,
+    Pit,
+    Door,
+    Cracked(isize),
+    Spikes,
+    Sludge,
+    Teleporter,
+}
+impl Terrain {
+    pub fn blocks_movement(self) -> bool {
+        match self {
+            Terrain::Wall | Terrain::Pit | Terrain::Door | Terrain::Cracked(_) => true,
+            Terrain::Floor
+            | Terrain::Water
+            | Terrain::Lava
+            | Terrain::Spikes
+            | Terrain::Sludge
+            | Terrain::Teleporter => false,
+        }
+    }
+    pub fn blocks_sight(self) -> bool {
+        matches!(self, Terrain::Wall | Terrain::Door | Terrain::Cracked(_))
+    }
+    pub fn sprite_name(self) -> Option<&'static str> {
+        match self {
+            Terrain::Floor => Some("floor"),
+            Terrain::Wall => None,
+            Terrain::Water => Some("water"),
+            Terrain::Lava => Some("lava"),
+            Terrain::Pit => Some("pit"),
+            Terrain::Door => Some("door"),
+            Terrain::Cracked(_) => Some("cracked_wall"),
+            Terrain::Spikes => Some("spikes_down"),
+            Terrain::Sludge => Some("sludge"),
+            Terrain::Teleporter => Some("teleporter"),
+        }
+    }
+    pub fn color(self, colors: &Colors) -> Color {
+        match self {
+            Terrain::Floor => colors.floor,
+            Terrain::Wall => colors.wall,
+            Terrain::Water => colors.water,
+            Terrain::Lava => colors.lava,
+            Terrain::Pit => colors.pit,
+            Terrain::Door => colors.door,
+            Terrain::Cracked(_) => colors.cracked,
+            Terrain::Spikes => colors.spikes,
+            Terrain::Sludge => colors.sludge,
+            Terrain::Teleporter => colors.teleporter,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum TileContent {
+    Gun(Gun),
+    Item(Item),
+    Key,
+    Coins(u64),
+}
+
 #[derive(Clone, Debug)]
 pub struct Tile {
+    pub terrain: Terrain,
     pub is_start: bool,
     pub is_end: bool,
-    pub can_move: bool,
-    pub has_gun: bool,
+    pub content: Option<TileContent>,
 }
 impl Tile {
-    pub fn get_gun(&self, font: &Font, style: &FontStyle) -> Result<Option<Gun>> {
-        if !self.has_gun {
-            return Ok(None);
+    pub fn new(terrain: Terrain) -> Self {
+        Self {
+            terrain,
+            is_start: false,
+            is_end: false,
+            content: None,
         }
+    }
+    pub fn can_move(&self) -> bool {
+        !self.terrain.blocks_movement()
+    }
+    pub fn has_gun(&self) -> bool {
+        matches!(self.content, Some(TileContent::Gun(_)))
+    }
+    pub fn has_item(&self) -> bool {
+        matches!(self.content, Some(TileContent::Item(_)))
+    }
+    pub fn has_key(&self) -> bool {
+        matches!(self.content, Some(TileContent::Key))
+    }
+    pub fn random_gun() -> Gun {
         let mut rng = rand::thread_rng();
         let mut patterns = Vec::new();
         for _ in 1..rng.gen_range(2, 4) {
@@ -30,34 +112,69 @@ impl Tile {
             patterns.push(pattern)
         }
 
-        Ok(Some(Gun::new(
+        let mut gun = Gun::new(
             rng.gen_range(15, 25),
             patterns,
             rng.gen_range(-10, 10),
             rng.gen_range(10., 20.),
-            font,
-            style,
             &get_random_name(),
             rng.gen(),
-        )?))
+        );
+        gun.can_toggle = rng.gen_range(0, 100) < 30;
+        if rng.gen_range(0, 100) < 25 {
+            gun.affix = StatusKind::ALL.choose(&mut rng).copied();
+        }
+        gun
     }
 
-    pub fn draw(&self, loc2: (usize, usize), window: &mut Window, z: i32, player: &Player) {
-        let loc = player.grid_to_screen(&(loc2.0 as f32, loc2.1 as f32));
-        let to_draw = if self.has_gun {
-            Color::YELLOW
-        } else if self.can_move {
-            if self.is_start {
-                Color::PURPLE
-            } else if self.is_end {
-                Color::GREEN
-            } else {
-                Color::from_rgba(128, 64, 128, 1.)
+    pub fn rare_gun() -> Gun {
+        let mut rng = rand::thread_rng();
+        let mut patterns = Vec::new();
+        for _ in 0..rng.gen_range(3, 5) {
+            let mut pattern = Vec::new();
+            for _ in 0..rng.gen_range(2, 4) {
+                pattern.push(rng.gen_range(0, 4))
             }
-        } else {
-            Color::BLACK
-        };
-        let rec = Rectangle::new(loc, (32, 32));
-        window.draw_ex(&rec, Col(to_draw), Transform::IDENTITY, z);
+            patterns.push(pattern)
+        }
+        let damage = rng.gen_range(8, 14);
+        let mut gun = Gun::new(
+            rng.gen_range(8, 14),
+            patterns,
+            if rng.gen() { damage } else { -damage },
+            rng.gen_range(16., 24.),
+            &get_random_name(),
+            rng.gen(),
+        );
+        gun.can_toggle = true;
+        gun.rarity = Rarity::Rare;
+        gun.affix = StatusKind::ALL.choose(&mut rng).copied();
+        gun
+    }
+
+    pub fn sprite_name(&self) -> Option<&'static str> {
+        match &self.content {
+            Some(TileContent::Gun(_)) => Some("gun"),
+            Some(TileContent::Item(_)) => Some("item"),
+            Some(TileContent::Key) => Some("key"),
+            Some(TileContent::Coins(_)) => Some("coins"),
+            None if self.terrain != Terrain::Floor => self.terrain.sprite_name(),
+            None if self.is_start => Some("start"),
+            None if self.is_end => Some("end"),
+            None => self.terrain.sprite_name(),
+        }
+    }
+
+    pub fn color(&self, colors: &Colors) -> Color {
+        match &self.content {
+            Some(TileContent::Gun(_)) => colors.gun,
+            Some(TileContent::Item(_)) => colors.item,
+            Some(TileContent::Key) => colors.key,
+            Some(TileContent::Coins(_)) => colors.coins,
+            None if self.terrain != Terrain::Floor => self.terrain.color(colors),
+            None if self.is_start => colors.start,
+            None if self.is_end => colors.end,
+            None => colors.floor,
+        }
     }
 }
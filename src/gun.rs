@@ -1,11 +1,11 @@
-use quicksilver::graphics::Font;
-use quicksilver::graphics::FontStyle;
-use quicksilver::graphics::Image;
-use quicksilver::Result;
 use rand::distributions::Distribution;
 use rand::distributions::Standard;
 use rand::Rng;
 
+use crate::polarity::Polarity;
+use crate::stats::Stat;
+use crate::status::StatusKind;
+
 use rand::seq::SliceRandom;
 
 pub fn get_random_name() -> String {
@@ -23,20 +23,27 @@ pub fn get_random_name() -> String {
     }
     name.into_iter().collect()
 }
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rarity {
+    Common,
+    Rare,
+}
+
 #[derive(Clone, Debug)]
 pub struct Gun {
-    pub cooldown: usize,
+    pub cooldown: Stat,
     pub patterns: Vec<Vec<i8>>,
-    pub damage: isize,
-    pub speed: f32,
-    pub rendered_name: Image,
+    pub damage: Stat,
+    pub speed: Stat,
+    pub name: String,
     pub shape: ShapeChoise,
-    pub rendered_patterns: Vec<Image>,
-    pub rendered_damage: Image,
-    pub rendered_cooldown: Image,
+    pub can_toggle: bool,
+    pub rarity: Rarity,
+    pub affix: Option<StatusKind>,
+    pub polarity: Polarity,
 }
 impl Gun {
-    pub fn new_random(font: &Font, style: &FontStyle) -> Result<Self> {
+    pub fn new_random() -> Self {
         let mut rng = rand::thread_rng();
         let mut patterns = Vec::new();
         for _ in 1..4 {
@@ -52,8 +59,6 @@ impl Gun {
             patterns,
             rng.gen_range(-7, 7),
             rng.gen_range(10., 20.),
-            font,
-            style,
             &get_random_name(),
             rng.gen(),
         )
@@ -63,52 +68,39 @@ impl Gun {
         patterns: Vec<Vec<i8>>,
         damage: isize,
         speed: f32,
-        font: &Font,
-        style: &FontStyle,
         name: &str,
         shape: ShapeChoise,
-    ) -> Result<Self> {
-        let rendered_name = font.render(&format!("Name: {:0}", name), style)?;
-        let mut rendered_patterns = Vec::new();
-        for pattern in &patterns {
-            let pattern: String = pattern
-                .iter()
-                .map(|v| {
-                    if *v < 0 {
-                        v + 4
-                    } else if *v > 3 {
-                        v - 4
-                    } else {
-                        *v
-                    }
-                })
-                .map(|v| {
-                    if v == 0 {
-                        'F'
-                    } else if v == 1 {
-                        'R'
-                    } else if v == 2 {
-                        'B'
-                    } else {
-                        'L'
-                    }
-                })
-                .collect();
-            rendered_patterns.push(font.render(&pattern, style)?);
-        }
-        let rendered_cooldown = font.render(&format!("Cooldown: {:0}", &cooldown), style)?;
-        let rendered_damage = font.render(&format!("Damage: {:0}", damage), style)?;
-        Ok(Gun {
-            rendered_name,
-            speed,
-            damage,
-            cooldown,
+    ) -> Self {
+        Gun {
+            name: name.to_string(),
+            speed: Stat::new(speed),
+            damage: Stat::new(damage.abs() as f32),
+            polarity: Polarity::of(damage),
+            cooldown: Stat::new(cooldown as f32),
             patterns,
-            rendered_patterns,
-            rendered_cooldown,
-            rendered_damage,
             shape,
-        })
+            can_toggle: false,
+            rarity: Rarity::Common,
+            affix: None,
+        }
+    }
+    pub fn damage(&self) -> isize {
+        self.polarity.signed(self.damage.value().round() as isize)
+    }
+    pub fn cooldown(&self) -> usize {
+        self.cooldown.value().round().max(0.) as usize
+    }
+    pub fn speed(&self) -> f32 {
+        self.speed.value()
+    }
+    pub fn polarity(&self) -> Polarity {
+        self.polarity
+    }
+    pub fn toggle_polarity(&mut self) -> bool {
+        if self.can_toggle {
+            self.polarity = self.polarity.flipped();
+        }
+        self.can_toggle
     }
 }
 #[derive(Debug, Clone, Copy)]
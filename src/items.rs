@@ -0,0 +1,71 @@
+use rand::Rng;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ItemEffect {
+    Heal(isize),
+    Shield(isize),
+    Bomb(isize),
+    Speed { percent: isize, duration: usize },
+}
+
+#[derive(Clone, Debug)]
+pub struct Item {
+    pub name: String,
+    pub effect: ItemEffect,
+}
+
+pub struct ItemRegistry {
+    items: Vec<(Item, u32)>,
+}
+impl ItemRegistry {
+    pub fn load() -> Self {
+        Self::parse(include_str!("../static/items.txt"))
+    }
+    pub fn parse(text: &str) -> Self {
+        let mut items = Vec::new();
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let value = match line.split_once('=') {
+                Some((_, value)) => value,
+                None => continue,
+            };
+            let fields: Vec<_> = value.split(',').map(str::trim).collect();
+            if let [name, effect, amount, duration, weight] = fields[..] {
+                let amount = amount.parse().unwrap_or(0);
+                let effect = match effect {
+                    "heal" => ItemEffect::Heal(amount),
+                    "shield" => ItemEffect::Shield(amount),
+                    "bomb" => ItemEffect::Bomb(amount),
+                    "speed" => ItemEffect::Speed {
+                        percent: amount,
+                        duration: duration.parse().unwrap_or(0),
+                    },
+                    _ => continue,
+                };
+                let item = Item {
+                    name: name.to_string(),
+                    effect,
+                };
+                items.push((item, weight.parse().unwrap_or(1)));
+            }
+        }
+        Self { items }
+    }
+    pub fn random(&self) -> Option<Item> {
+        let total: u32 = self.items.iter().map(|(_, weight)| weight).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut roll = rand::thread_rng().gen_range(0, total);
+        for (item, weight) in &self.items {
+            if roll < *weight {
+                return Some(item.clone());
+            }
+            roll -= weight;
+        }
+        None
+    }
+}
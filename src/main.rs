@@ -1,74 +1,87 @@
+use config::Config;
 use quicksilver::graphics::Image;
 use quicksilver::{
-    geom::Vector,
-    graphics::{Color, Font, FontStyle},
-    lifecycle::{run, Event, Settings, State, Window},
+    geom::{Rectangle, Vector},
+    graphics::{Color, Font, FontStyle, ResizeStrategy, View},
+    lifecycle::{run_with, Event, Settings, State, Window},
     Result,
 };
-use screens::screen::Screen;
+use screens::stack::ScreenStack;
 use screens::start::StartScreen;
 
 const CELL_SIZE: usize = 32;
 const PLAYER_SIZE: usize = 16;
 const GRID_SIZE: usize = 30;
 
+mod animation;
 mod bullet;
+mod camera;
+mod config;
+mod controls;
+mod floating_text;
 mod game_state;
+mod glitch;
 mod grid;
 mod gun;
+mod items;
+mod minimap;
 mod monster;
 mod moveable;
 mod player;
+mod polarity;
+mod progression;
 mod screens;
-
-pub fn calc_start(cam: f32, line_size: usize) -> usize {
-    let cam = cam.floor() as usize;
-    let halved = line_size / 2;
-    if cam < halved || cam == 1 {
-        0
-    } else {
-        let calced = cam - halved;
-        if calced <= 1 {
-            0
-        } else {
-            calced - 1
-        }
-    }
-}
+mod shop;
+mod stats;
+mod status;
+mod text_cache;
+mod tileset;
 
 pub struct MainState {
-    screen: Box<dyn Screen>,
+    screens: ScreenStack,
+    config: Config,
     font: Font,
     default_style: FontStyle,
+    viewport: Vector,
 
     _rendered_pattern_reminder: Image,
 }
-impl State for MainState {
-    fn new() -> Result<Self> {
+impl MainState {
+    fn new(config: Config) -> Result<Self> {
         let font = Font::from_bytes(include_bytes!("../static/font.ttf").to_vec())?;
-        let style = FontStyle::new(100.0, Color::WHITE);
+        let style = config.font_style();
 
-        let screen = Box::new(StartScreen::new()?);
+        let screens = ScreenStack::new(Box::new(StartScreen::new(&font, &style)?));
         let rendered_pattern_reminder = font.render("Patterns", &style)?;
         Ok(Self {
-            screen,
+            screens,
+            config,
             font,
             default_style: style,
+            viewport: Vector::ZERO,
             _rendered_pattern_reminder: rendered_pattern_reminder,
         })
     }
+}
+impl State for MainState {
+    fn new() -> Result<Self> {
+        MainState::new(Config::load())
+    }
     fn draw(&mut self, window: &mut Window) -> Result<()> {
+        let size = window.screen_size();
+        if size != self.viewport {
+            self.viewport = size;
+            window.set_view(View::new(Rectangle::new_sized(size)));
+        }
         window.clear(Color::BLACK)?;
-        self.screen.draw(window, &self.font, &self.default_style)?;
+        self.screens
+            .draw(window, &self.config, &self.font, &self.default_style)?;
         Ok(())
     }
     fn update(&mut self, window: &mut Window) -> Result<()> {
-        let next_screen = self
-            .screen
-            .update(window, &self.font, &self.default_style)?;
-        if let Some(next_screen) = next_screen {
-            self.screen = next_screen;
-        }
+        self.screens
+            .update(window, &mut self.config, &self.font, &self.default_style)?;
+        self.default_style = self.config.font_style();
         Ok(())
     }
     fn event(&mut self, _event: &Event, _window: &mut Window) -> Result<()> {
@@ -77,5 +90,14 @@ impl State for MainState {
 }
 
 pub fn main() {
-    run::<MainState>("Glitch Dungeon", Vector::new(800, 600), Settings::default());
+    let config = Config::load();
+    let size = Vector::new(config.width, config.height);
+    let settings = Settings {
+        fullscreen: config.fullscreen,
+        resize: ResizeStrategy::Stretch,
+        ..Settings::default()
+    };
+    run_with("Glitch Dungeon", size, settings, move || {
+        MainState::new(config)
+    });
 }
@@ -0,0 +1,183 @@
+use crate::config::Colors;
+use crate::grid::{Grid, Terrain, Tile, Visibility};
+use crate::monster::Monster;
+use crate::player::Player;
+use quicksilver::geom::{Rectangle, Transform, Vector};
+use quicksilver::graphics::{Background::Col, Color};
+use quicksilver::lifecycle::Window;
+use std::cell::RefCell;
+
+const CORNER_SIZE: f32 = 160.;
+const MARGIN: f32 = 10.;
+const MIN_MARKER_SIZE: f32 = 3.;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mark {
+    Floor,
+    End,
+    Gun,
+    Key,
+    Door,
+}
+impl Mark {
+    fn of(tile: &Tile) -> Option<Mark> {
+        if tile.terrain == Terrain::Door {
+            Some(Mark::Door)
+        } else if !tile.can_move() {
+            None
+        } else if tile.has_key() {
+            Some(Mark::Key)
+        } else if tile.has_gun() {
+            Some(Mark::Gun)
+        } else if tile.is_end {
+            Some(Mark::End)
+        } else {
+            Some(Mark::Floor)
+        }
+    }
+    fn color(self, colors: &Colors) -> Color {
+        match self {
+            Mark::Floor => colors.floor,
+            Mark::End => colors.end,
+            Mark::Gun => colors.gun,
+            Mark::Key => colors.key,
+            Mark::Door => colors.door,
+        }
+    }
+}
+
+struct Layout {
+    revision: usize,
+    block: usize,
+    runs: Vec<((usize, usize), usize, Mark)>,
+}
+
+pub struct Minimap {
+    pub expanded: bool,
+    layout: RefCell<Option<Layout>>,
+}
+impl Minimap {
+    pub fn new() -> Self {
+        Self {
+            expanded: false,
+            layout: RefCell::new(None),
+        }
+    }
+    fn build_runs(grid: &Grid, block: usize) -> Vec<((usize, usize), usize, Mark)> {
+        let mut runs: Vec<((usize, usize), usize, Mark)> = Vec::new();
+        for by in 0..grid.height.div_ceil(block) {
+            for bx in 0..grid.length.div_ceil(block) {
+                let start = (bx * block, by * block);
+                let end = (start.0 + block - 1, start.1 + block - 1);
+                let mark = grid
+                    .iter_area(start, end)
+                    .filter(|(loc, _)| grid.visibility_at(*loc) != Visibility::Unseen)
+                    .filter_map(|(_, tile)| Mark::of(tile))
+                    .max_by_key(|v| *v as u8);
+                let mark = match mark {
+                    Some(mark) => mark,
+                    None => continue,
+                };
+                match runs.last_mut() {
+                    Some((first, amount, run_mark))
+                        if first.1 == by && first.0 + *amount == bx && *run_mark == mark =>
+                    {
+                        *amount += 1
+                    }
+                    _ => runs.push(((bx, by), 1, mark)),
+                }
+            }
+        }
+        runs
+    }
+    pub fn toggle(&mut self) {
+        self.expanded = !self.expanded;
+    }
+    fn area(&self, screen: Vector, grid: &Grid) -> (Vector, f32) {
+        let cells = grid.length.max(grid.height) as f32;
+        let size = if self.expanded {
+            screen.x.min(screen.y) - MARGIN * 4.
+        } else {
+            CORNER_SIZE
+        };
+        let cell_size = size / cells;
+        let map_size = Vector::new(
+            cell_size * grid.length as f32,
+            cell_size * grid.height as f32,
+        );
+        let pos = if self.expanded {
+            (screen - map_size) / 2
+        } else {
+            Vector::new(screen.x - map_size.x - MARGIN, MARGIN)
+        };
+        (pos, cell_size)
+    }
+    pub fn draw(
+        &self,
+        window: &mut Window,
+        grid: &Grid,
+        player: &Player,
+        monsters: &[Monster],
+        colors: &Colors,
+    ) {
+        let (pos, cell_size) = self.area(window.screen_size(), grid);
+        let cell_rect = |cell: (usize, usize), shrink: f32| {
+            let size = (cell_size - shrink * 2.).max(MIN_MARKER_SIZE);
+            let center = pos
+                + (Vector::new(cell.0 as f32, cell.1 as f32) + Vector::new(0.5, 0.5)) * cell_size;
+            Rectangle::new(center - Vector::new(size, size) / 2., (size, size))
+        };
+        window.draw_ex(
+            &Rectangle::new(
+                pos - Vector::new(2, 2),
+                Vector::new(grid.length as f32, grid.height as f32) * cell_size + Vector::new(4, 4),
+            ),
+            Col(Color::BLACK.with_alpha(0.7)),
+            Transform::IDENTITY,
+            0,
+        );
+        let block = (1. / cell_size).ceil().max(1.) as usize;
+        let mut layout = self.layout.borrow_mut();
+        if !layout
+            .as_ref()
+            .is_some_and(|v| v.revision == grid.revision && v.block == block)
+        {
+            *layout = Some(Layout {
+                revision: grid.revision,
+                block,
+                runs: Minimap::build_runs(grid, block),
+            });
+        }
+        if let Some(layout) = layout.as_ref() {
+            let block_size = cell_size * block as f32;
+            for ((x, y), amount, mark) in &layout.runs {
+                window.draw_ex(
+                    &Rectangle::new(
+                        pos + Vector::new(*x as f32, *y as f32) * block_size,
+                        (block_size * *amount as f32, block_size),
+                    ),
+                    Col(mark.color(colors)),
+                    Transform::IDENTITY,
+                    1,
+                );
+            }
+        }
+        for monster in monsters
+            .iter()
+            .filter(|v| grid.visibility_at(v.location.cell_loc) == Visibility::Visible)
+        {
+            window.draw_ex(
+                &cell_rect(monster.location.cell_loc, cell_size / 4.),
+                Col(colors.monster),
+                Transform::IDENTITY,
+                2,
+            );
+        }
+        window.draw_ex(
+            &cell_rect(player.location.cell_loc, cell_size / 6.),
+            Col(colors.player),
+            Transform::IDENTITY,
+            3,
+        );
+    }
+}
@@ -1,53 +1,93 @@
+use crate::animation::{AnimationState, ATTACK, HIT_FLASH, MONSTER_IDLE};
+use crate::camera::Camera;
+use crate::config::Colors;
+use crate::glitch::draw_channel_split;
 use crate::grid::grid::Grid;
+use crate::grid::hazard::speed_factor;
 use crate::grid::Dir;
 use crate::moveable::Moveable;
 use crate::player::Player;
+use crate::polarity::{Polarity, HEAL_CAP};
+use crate::stats::{Modifier, Source, Stat};
+use crate::status::{StatusEffects, StatusKind};
+use crate::tileset::{monster_prefix, Tileset};
 use crate::CELL_SIZE;
 use quicksilver::geom::Rectangle;
-use quicksilver::geom::Shape;
 use quicksilver::geom::Transform;
 use quicksilver::geom::Vector;
-use quicksilver::graphics::Color;
-use quicksilver::graphics::Font;
-use quicksilver::graphics::FontStyle;
-use quicksilver::graphics::Image;
 use quicksilver::lifecycle::Window;
-use quicksilver::prelude::{Col, Img};
-use quicksilver::Result;
+use quicksilver::prelude::{Blended, Col, Img};
 use rand::Rng;
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DamageOutcome {
+    Cooldown,
+    Blocked,
+    Damaged(isize),
+    Healed(isize),
+}
+
+const SWAP_TIME: usize = 300;
+const SWAP_WARNING: usize = 60;
+
 pub struct Monster {
     pub location: Moveable,
     pub size: usize,
     pub health: isize,
-    pub started_negative: bool,
-    pub damage: isize,
-    pub speed: f32,
-    pub rendered_health: Image,
+    pub polarity: Polarity,
+    pub swap_timer: Option<usize>,
+    pub damage: Stat,
+    pub speed: Stat,
     pub damage_cooldown: usize,
+    pub animation: AnimationState,
+    pub hit_flash: AnimationState,
+    pub last_hit: DamageOutcome,
+    pub status: StatusEffects,
+    pub attack_status: Option<StatusKind>,
 }
 impl Monster {
-    pub fn new(location: Vector, font: &Font, style: &FontStyle) -> Result<Self> {
+    pub fn new(location: Vector, floor: usize) -> Self {
         let mut rng = rand::thread_rng();
         let health = rng.gen_range(-10, 10);
-        let rendered_health = font.render(&health.to_string(), style)?;
-        Ok(Self {
+        let difficulty = floor.saturating_sub(1) as f32;
+        let mut damage = Stat::new(5.);
+        damage.add(Source::Floor, Modifier::Add(difficulty));
+        let mut speed = Stat::new(5.);
+        speed.add(Source::Floor, Modifier::Multiply(1. + 0.05 * difficulty));
+        Self {
             location: Moveable::new(location),
             size: 15,
             health,
-            damage: 5,
-            speed: 5.,
-            started_negative: health < 0,
-            rendered_health,
+            damage,
+            speed,
+            polarity: Polarity::of(health),
+            swap_timer: if rng.gen_range(0, 10) == 0 {
+                Some(SWAP_TIME)
+            } else {
+                None
+            },
             damage_cooldown: 0,
-        })
+            animation: AnimationState::new(MONSTER_IDLE),
+            hit_flash: AnimationState::stopped(HIT_FLASH),
+            last_hit: DamageOutcome::Cooldown,
+            status: StatusEffects::default(),
+            attack_status: match rng.gen_range(0, 10) {
+                0 | 1 => Some(StatusKind::Poison),
+                2 => Some(StatusKind::Slow),
+                3 if health < 0 => Some(StatusKind::Corrupt),
+                _ => None,
+            },
+        }
     }
     pub fn move_a_bit(&mut self, grid: &Grid, player: &Player) {
         if self.damage_cooldown > 0 {
             self.damage_cooldown -= 1;
         }
+        self.animation.update();
+        self.hit_flash.update();
+        self.update_swap();
         let mut rng = rand::thread_rng();
-        let dir = if rng.gen_range(0, 10) > 7 {
+        let dir = if rng.gen_range(0, 10) > 7 && !self.status.has(StatusKind::Corrupt) {
             if self.location.cell_loc.0 > player.location.cell_loc.0 {
                 Dir::Left
             } else if self.location.cell_loc.0 < player.location.cell_loc.0 {
@@ -62,51 +102,114 @@ impl Monster {
         } else {
             rng.gen()
         };
-        self.location.move_some(dir, self.speed, grid, self.size);
+        self.speed.remove(Source::Status);
+        let status_factor = self.status.speed_factor();
+        if status_factor != 1. {
+            self.speed
+                .add(Source::Status, Modifier::Multiply(status_factor));
+        }
+        let speed = (self.speed.value() * speed_factor(grid, self.location.cell_loc))
+            .min((CELL_SIZE - self.size) as f32);
+        if speed == 0. {
+            return;
+        }
+        self.location.move_some(dir, speed, grid, self.size);
     }
-    pub fn get_damage(&mut self, damage: isize, font: &Font, style: &FontStyle) -> Result<bool> {
+    pub fn get_damage(&mut self, damage: isize) -> DamageOutcome {
         if self.damage_cooldown > 0 {
-            return Ok(self.is_alive());
+            return DamageOutcome::Cooldown;
         }
-        if (!self.started_negative) && damage < 0 {
-            if self.health - damage < 12 {
-                self.health -= damage;
-                self.damage_cooldown = 20;
-                self.rendered_health = font.render(&self.health.to_string(), style)?;
-            }
-        } else if self.started_negative && damage > 0 {
-            if self.health - damage > -12 {
+        let outcome = if damage != 0 && Polarity::of(damage) != self.polarity {
+            if (self.health - damage).abs() < HEAL_CAP {
                 self.health -= damage;
                 self.damage_cooldown = 20;
-                self.rendered_health = font.render(&self.health.to_string(), style)?;
+                DamageOutcome::Healed(damage)
+            } else {
+                DamageOutcome::Blocked
             }
         } else {
             self.health -= damage;
             self.damage_cooldown = 20;
-            self.rendered_health = font.render(&self.health.to_string(), style)?;
+            DamageOutcome::Damaged(damage)
+        };
+        self.last_hit = outcome;
+        self.hit_flash.play(HIT_FLASH);
+        outcome
+    }
+    pub fn damage(&self) -> isize {
+        self.damage.value().round() as isize
+    }
+    pub fn take_tick_damage(&mut self, amount: isize) -> DamageOutcome {
+        let damage = self.polarity.signed(amount);
+        self.health -= damage;
+        self.last_hit = DamageOutcome::Damaged(damage);
+        self.hit_flash.play(HIT_FLASH);
+        self.last_hit
+    }
+    pub fn is_flashing(&self) -> bool {
+        !self.hit_flash.is_finished()
+    }
+    fn update_swap(&mut self) {
+        if let Some(timer) = &mut self.swap_timer {
+            if *timer > 0 {
+                *timer -= 1;
+                return;
+            }
+            *timer = SWAP_TIME;
+            self.polarity = self.polarity.flipped();
+            self.health = -self.health;
         }
-        Ok(self.is_alive())
+    }
+    pub fn is_about_to_swap(&self) -> bool {
+        self.swap_timer.is_some_and(|v| v < SWAP_WARNING)
+    }
+    pub fn attack(&mut self) {
+        self.animation.play(ATTACK);
     }
     pub fn is_alive(&self) -> bool {
-        (self.started_negative && self.health < 0) || ((!self.started_negative) && self.health > 0)
+        match self.polarity {
+            Polarity::Positive => self.health > 0,
+            Polarity::Negative => self.health < 0,
+        }
+    }
+    pub fn draw(
+        &self,
+        window: &mut Window,
+        z: i32,
+        camera: &Camera,
+        colors: &Colors,
+        tileset: &Tileset,
+        glitch: f32,
+    ) {
+        let monster_rec = self.get_rectangle(camera);
+        match tileset.get(&self.animation.sprite_name(monster_prefix(self.polarity))) {
+            Some(sprite) => {
+                draw_channel_split(window, &monster_rec, sprite, glitch, z);
+                window.draw_ex(&monster_rec, Img(sprite), Transform::IDENTITY, z);
+                if self.is_flashing() && self.hit_flash.frame().is_multiple_of(2) {
+                    let flash = match self.last_hit {
+                        DamageOutcome::Healed(_) => colors.heal,
+                        DamageOutcome::Blocked => colors.blocked,
+                        _ => colors.damage,
+                    };
+                    window.draw_ex(
+                        &monster_rec,
+                        Blended(sprite, flash.with_alpha(0.7)),
+                        Transform::IDENTITY,
+                        z,
+                    );
+                }
+            }
+            None => window.draw_ex(&monster_rec, Col(colors.monster), Transform::IDENTITY, z),
+        }
+    }
+    pub fn get_rectangle(&self, camera: &Camera) -> Rectangle {
+        camera.rectangle(
+            self.location.location,
+            Vector::new(self.size as f32, self.size as f32),
+        )
     }
-    pub fn draw(&self, window: &mut Window, z: i32, player: &Player) {
-        let screen_pos = player.grid_to_screen(&(
-            self.location.location.x / CELL_SIZE as f32,
-            self.location.location.y / CELL_SIZE as f32,
-        ));
-        let mut monster_rec =
-            Rectangle::new(screen_pos.clone(), (self.size as f32, self.size as f32))
-                .with_center(screen_pos);
-        window.draw_ex(&monster_rec, Col(Color::INDIGO), Transform::IDENTITY, z);
-        monster_rec.pos.y += 20.;
-        monster_rec.size.y = 15.;
-        monster_rec.size.x = 20.;
-        window.draw_ex(
-            &monster_rec,
-            Img(&self.rendered_health),
-            Transform::IDENTITY,
-            z,
-        );
+    pub fn health_label(&self, camera: &Camera) -> Rectangle {
+        camera.label_below(&self.get_rectangle(camera))
     }
 }
@@ -1,6 +1,8 @@
 use crate::grid::dir::Dir;
 use crate::grid::grid::Grid;
-use crate::grid::tile::Tile;
+use crate::grid::hazard::hazard_at;
+use crate::grid::tile::Terrain;
+use crate::grid::Hazard;
 use crate::CELL_SIZE;
 use quicksilver::geom::Vector;
 
@@ -46,7 +48,7 @@ impl Moveable {
         speed: f32,
         grid: &Grid,
         moveable_size: usize,
-    ) -> Option<Option<((usize, usize), Tile)>> {
+    ) -> Option<Option<((usize, usize), Terrain)>> {
         let moveable_size_as_f32 = moveable_size as f32;
         let half_moveable_size = moveable_size_as_f32 / 2.;
         match dir {
@@ -56,13 +58,13 @@ impl Moveable {
                     != self.cell_loc.0 as isize
                 {
                     if self.cell_loc.0 != 0 {
-                        let next_cell = grid.get_cell((self.cell_loc.0 - 1, self.cell_loc.1));
-                        match next_cell {
-                            Some(next_cell) => {
-                                if !(next_cell.1).can_move {
+                        let next = (self.cell_loc.0 - 1, self.cell_loc.1);
+                        match grid.get_tile(next) {
+                            Some(tile) => {
+                                if !tile.can_move() {
                                     self.location.x =
                                         (self.cell_loc.0 * CELL_SIZE + (moveable_size / 2)) as f32;
-                                    return Some(Some(next_cell));
+                                    return Some(Some((next, tile.terrain)));
                                 } else {
                                     self.location = new_loc;
                                     self.cell_loc =
@@ -83,14 +85,14 @@ impl Moveable {
                 if ((new_loc.x + half_moveable_size) / CELL_SIZE as f32).floor() as usize
                     != self.cell_loc.0
                 {
-                    let next_cell = grid.get_cell((self.cell_loc.0 + 1, self.cell_loc.1));
-                    match next_cell {
-                        Some(next_cell) => {
-                            if !(next_cell.1).can_move {
+                    let next = (self.cell_loc.0 + 1, self.cell_loc.1);
+                    match grid.get_tile(next) {
+                        Some(tile) => {
+                            if !tile.can_move() {
                                 self.location.x = (self.cell_loc.0 * CELL_SIZE + CELL_SIZE
                                     - (moveable_size / 2))
                                     as f32;
-                                return Some(Some(next_cell));
+                                return Some(Some((next, tile.terrain)));
                             } else {
                                 self.location = new_loc;
                                 self.cell_loc = (new_loc.x as usize / CELL_SIZE, self.cell_loc.1);
@@ -108,13 +110,13 @@ impl Moveable {
                     != self.cell_loc.1 as isize
                 {
                     if self.cell_loc.1 != 0 {
-                        let next_cell = grid.get_cell((self.cell_loc.0, self.cell_loc.1 - 1));
-                        match next_cell {
-                            Some(next_cell) => {
-                                if !(next_cell.1).can_move {
+                        let next = (self.cell_loc.0, self.cell_loc.1 - 1);
+                        match grid.get_tile(next) {
+                            Some(tile) => {
+                                if !tile.can_move() {
                                     self.location.y =
                                         (self.cell_loc.1 * CELL_SIZE + (moveable_size / 2)) as f32;
-                                    return Some(Some(next_cell));
+                                    return Some(Some((next, tile.terrain)));
                                 } else {
                                     self.location = new_loc;
                                     self.cell_loc =
@@ -135,14 +137,14 @@ impl Moveable {
                 if ((new_loc.y + half_moveable_size) / CELL_SIZE as f32).floor() as usize
                     != self.cell_loc.1 as usize
                 {
-                    let next_cell = grid.get_cell((self.cell_loc.0, self.cell_loc.1 + 1));
-                    match next_cell {
-                        Some(next_cell) => {
-                            if !(next_cell.1).can_move {
+                    let next = (self.cell_loc.0, self.cell_loc.1 + 1);
+                    match grid.get_tile(next) {
+                        Some(tile) => {
+                            if !tile.can_move() {
                                 self.location.y = (self.cell_loc.1 * CELL_SIZE + CELL_SIZE
                                     - (moveable_size / 2))
                                     as f32;
-                                return Some(Some(next_cell));
+                                return Some(Some((next, tile.terrain)));
                             } else {
                                 self.location = new_loc;
                                 self.cell_loc = (self.cell_loc.0, new_loc.y as usize / CELL_SIZE);
@@ -157,6 +159,35 @@ impl Moveable {
         }
         None
     }
+    pub fn move_by(
+        &mut self,
+        delta: Vector,
+        grid: &Grid,
+        moveable_size: usize,
+    ) -> Option<Option<((usize, usize), Terrain)>> {
+        let horizontal = if delta.x < 0. { Dir::Left } else { Dir::Right };
+        if delta.x != 0. {
+            if let Some(hit) = self.move_some(horizontal, delta.x.abs(), grid, moveable_size) {
+                return Some(hit);
+            }
+        }
+        let vertical = if delta.y < 0. { Dir::Up } else { Dir::Down };
+        if delta.y != 0. {
+            if let Some(hit) = self.move_some(vertical, delta.y.abs(), grid, moveable_size) {
+                return Some(hit);
+            }
+        }
+        None
+    }
+    pub fn hazard(&self, grid: &Grid, previous: (usize, usize)) -> Option<Hazard> {
+        hazard_at(grid, self.cell_loc, self.cell_loc != previous)
+    }
+    pub fn teleport(&mut self, cell: (usize, usize)) {
+        self.reset_location(Vector::new(
+            (cell.0 * CELL_SIZE) as f32,
+            (cell.1 * CELL_SIZE) as f32,
+        ));
+    }
     pub fn reset_location(&mut self, location: Vector) {
         let cell_lock = (
             location.x as usize / CELL_SIZE,
@@ -1,10 +1,19 @@
+use crate::animation::{AnimationState, HURT, IDLE, WALK};
+use crate::camera::Camera;
+use crate::config::Colors;
+use crate::controls::{Control, Controls};
+use crate::glitch::draw_channel_split;
 use crate::grid::grid::Grid;
-use crate::grid::Dir;
+use crate::grid::hazard::speed_factor;
+use crate::grid::{Dir, Terrain};
 use crate::moveable::Moveable;
+use crate::progression::{xp_for_level, Upgrade};
+use crate::stats::{Modifier, ModifierId, Source, Stat};
+use crate::status::{StatusEffects, StatusKind};
+use crate::tileset::{player_prefix, Tileset};
 use crate::CELL_SIZE;
 use crate::PLAYER_SIZE;
 use quicksilver::geom::Rectangle;
-use quicksilver::graphics::{Font, FontStyle, Image};
 use quicksilver::lifecycle::Window;
 use quicksilver::Result;
 use quicksilver::{
@@ -12,13 +21,15 @@ use quicksilver::{
     {
         input::ButtonState,
         input::Keyboard,
-        prelude::{Col, Img, Key},
+        input::MouseButton,
+        prelude::{Blended, Col, Img, Key},
     },
 };
 
 use crate::gun::Gun;
+use crate::items::{Item, ItemEffect};
 use quicksilver::geom::Transform;
-use quicksilver::graphics::Color;
+use rand::Rng;
 
 pub fn check_multiple(board: &Keyboard, to_check: &[Key]) -> bool {
     to_check
@@ -36,54 +47,75 @@ pub fn check_multiple_pressed(board: &Keyboard, to_check: &[Key]) -> bool {
         .any(|v| v)
 }
 
+const BASE_HEALTH: isize = 100;
+pub const MAX_ITEMS: usize = 3;
+const MAX_GUNS: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AimMode {
+    Keys,
+    Mouse,
+}
+
 #[derive(Clone)]
 pub struct Player {
     pub location: Moveable,
-    pub speed: f32,
-    pub dir: Dir,
+    pub speed: Stat,
+    pub aim: f32,
+    pub aim_mode: AimMode,
     pub health: isize,
-    pub invis_timer: usize,
+    pub animation: AnimationState,
+    pub hurt: AnimationState,
     pub guns: Vec<Gun>,
     pub selected_gun: usize,
     pub shoot_timer: usize,
-    pub rendered_health: Image,
+    pub items: Vec<Item>,
+    pub shield: isize,
+    pub speed_timer: usize,
+    pub speed_boost: Option<ModifierId>,
+    pub keys: usize,
+    pub coins: u64,
+    pub xp: u64,
+    pub level: usize,
+    pub pending_levels: usize,
+    pub upgrades: Vec<Upgrade>,
+    pub max_health: Stat,
+    pub cooldown_scale: Stat,
+    pub gun_slots: Stat,
+    pub status: StatusEffects,
 }
 impl Player {
-    pub fn get_outer_cell_points(&self) -> ((usize, usize), (usize, usize)) {
-        let height = 600;
-        let width = 800;
-        let mid_point = {
-            let mut mid_point = self.location.location.clone();
-            if mid_point.x < 0. {
-                mid_point.x = 0.;
-            }
-            if mid_point.y < 0. {
-                mid_point.y = 0.;
-            }
-            mid_point
-        };
-        let start_x = crate::calc_start(mid_point.x / CELL_SIZE as f32, 800 / CELL_SIZE);
-        let start_y = crate::calc_start(mid_point.y / CELL_SIZE as f32, 600 / CELL_SIZE);
-        let end_x = 1 + start_x + width;
-        let end_y = 1 + start_y + height;
-        ((start_x, start_y), (end_x, end_y))
-    }
-    pub fn new(loc: (usize, usize), font: &Font, style: &FontStyle) -> Result<Self> {
+    pub fn new(loc: (usize, usize)) -> Self {
         let loc = Vector::new((loc.0 * CELL_SIZE) as i32, (loc.1 * CELL_SIZE) as i32);
-        let guns = vec![Gun::new_random(font, style)?, Gun::new_random(font, style)?];
-        let health = 100;
-        let rendered_health = font.render(&health.to_string(), &style)?;
-        Ok(Self {
-            rendered_health,
+        let mut guns = vec![Gun::new_random(), Gun::new_random()];
+        guns[0].can_toggle = true;
+        let health = BASE_HEALTH;
+        Self {
             location: Moveable::new(loc),
-            speed: 10.,
-            dir: Dir::Up,
+            speed: Stat::new(10.),
+            aim: Dir::Up.angle(),
+            aim_mode: AimMode::Keys,
             health,
-            invis_timer: 30,
+            animation: AnimationState::new(IDLE),
+            hurt: AnimationState::once(HURT),
             guns,
             selected_gun: 0,
             shoot_timer: 0,
-        })
+            items: Vec::new(),
+            shield: 0,
+            speed_timer: 0,
+            speed_boost: None,
+            keys: 0,
+            coins: 0,
+            xp: 0,
+            level: 1,
+            pending_levels: 0,
+            upgrades: Vec::new(),
+            max_health: Stat::new(BASE_HEALTH as f32),
+            cooldown_scale: Stat::new(1.),
+            gun_slots: Stat::new(MAX_GUNS as f32),
+            status: StatusEffects::default(),
+        }
     }
     pub fn reset_location(&mut self, location: Vector) {
         self.location.reset_location(location);
@@ -91,83 +123,107 @@ impl Player {
     pub fn update(
         &mut self,
         window: &Window,
+        controls: &Controls,
+        camera: &Camera,
         grid: &mut Grid,
-        font: &Font,
-        style: &FontStyle,
     ) -> Result<(u64, Action)> {
-        let board = window.keyboard();
-        if check_multiple(board, &[Key::A]) {
-            self.location
-                .move_some(Dir::Left, self.speed, grid, PLAYER_SIZE);
+        if self.speed_timer > 0 {
+            self.speed_timer -= 1;
+        } else if let Some(boost) = self.speed_boost.take() {
+            self.speed.remove_modifier(boost);
         }
-        if check_multiple(board, &[Key::D]) {
-            self.location
-                .move_some(Dir::Right, self.speed, grid, PLAYER_SIZE);
+        self.speed.remove(Source::Status);
+        let status_factor = self.status.speed_factor();
+        if status_factor != 1. {
+            self.speed
+                .add(Source::Status, Modifier::Multiply(status_factor));
         }
-        if check_multiple(board, &[Key::W]) {
-            self.location
-                .move_some(Dir::Up, self.speed, grid, PLAYER_SIZE);
-        }
-        if check_multiple(board, &[Key::S]) {
-            self.location
-                .move_some(Dir::Down, self.speed, grid, PLAYER_SIZE);
-        }
-        if check_multiple(board, &[Key::Up]) {
-            self.dir = Dir::Up
-        }
-        if check_multiple(board, &[Key::Down]) {
-            self.dir = Dir::Down
+        let movement = controls.movement(window);
+        if movement != Vector::ZERO {
+            let speed = self.move_speed() * speed_factor(grid, self.location.cell_loc);
+            let hit = self.location.move_by(movement * speed, grid, PLAYER_SIZE);
+            if let Some(Some((cell, terrain))) = hit {
+                if terrain == Terrain::Door && self.keys > 0 && grid.open_door(cell) {
+                    self.keys -= 1;
+                }
+            }
+            self.animation.play(WALK);
+        } else {
+            self.animation.play(IDLE);
         }
-        if check_multiple(board, &[Key::Left]) {
-            self.dir = Dir::Left
+        self.animation.update();
+        self.hurt.update();
+        if controls.is_pressed(window, Control::ToggleAim) {
+            self.aim_mode = match self.aim_mode {
+                AimMode::Keys => AimMode::Mouse,
+                AimMode::Mouse => AimMode::Keys,
+            };
         }
-        if check_multiple(board, &[Key::Right]) {
-            self.dir = Dir::Right
+        match self.aim_mode {
+            AimMode::Keys => {
+                let aim = controls.aim(window);
+                if aim != Vector::ZERO {
+                    self.aim = aim.angle();
+                }
+            }
+            AimMode::Mouse => {
+                let towards = window.mouse().pos() - self.get_rectangle(camera).center();
+                if towards != Vector::ZERO {
+                    self.aim = towards.angle();
+                }
+            }
         }
-        if check_multiple_pressed(board, &[Key::Q]) {
+        if controls.is_pressed(window, Control::PreviousGun) {
             if self.selected_gun == 0 {
                 self.selected_gun = self.guns.len() - 1;
             } else {
                 self.selected_gun -= 1;
             }
         }
-        if check_multiple_pressed(board, &[Key::E]) {
+        if controls.is_pressed(window, Control::NextGun) {
             if self.selected_gun == self.guns.len() - 1 {
                 self.selected_gun = 0;
             } else {
                 self.selected_gun += 1;
             }
         }
+        if controls.is_pressed(window, Control::TogglePolarity) {
+            self.guns[self.selected_gun].toggle_polarity();
+        }
         let mut extra_points = 0;
-        let current = grid.get_cell(self.location.cell_loc);
+        let cell = self.location.cell_loc;
+        let is_end = grid.get_tile(cell).is_some_and(|v| v.is_end);
         if self.shoot_timer > 0 {
             self.shoot_timer -= 1;
         }
-        if let Some(current) = &current {
-            if current.1.has_gun {
-                if let Some(gun) = grid.get_gun(&self.location.cell_loc, font, style)? {
-                    self.guns.push(gun);
-                    extra_points += 20;
-                    if self.guns.len() > 4 {
-                        self.guns.remove(0);
-                    }
-                }
+        if let Some(gun) = grid.get_gun(&cell) {
+            self.add_gun(gun);
+            extra_points += 20;
+        }
+        if let Some(amount) = grid.take_coins(cell) {
+            self.coins += amount;
+        }
+        if grid.take_key(cell) {
+            self.keys += 1;
+        }
+        if self.items.len() < MAX_ITEMS {
+            if let Some(item) = grid.get_item(&cell) {
+                self.items.push(item);
             }
         }
         Ok((
             extra_points,
-            current
-                .and_then(|(_, tile)| {
-                    if tile.is_end {
-                        Some(Action::NextScreen)
-                    } else {
-                        None
-                    }
-                })
+            Some(Action::NextScreen)
+                .filter(|_| is_end)
                 .or_else(|| {
-                    if check_multiple(board, &[Key::F, Key::Space]) && self.shoot_timer == 0 {
+                    let mouse_shot = self.aim_mode == AimMode::Mouse
+                        && window.mouse()[MouseButton::Left].is_down();
+                    if (controls.is_down(window, Control::Shoot) || mouse_shot)
+                        && self.shoot_timer == 0
+                        && !self.status.has(StatusKind::Stun)
+                    {
                         let selected_gun = self.guns[self.selected_gun].clone();
-                        self.shoot_timer = selected_gun.cooldown;
+                        self.shoot_timer = self.cooldown(&selected_gun);
                         return Some(Action::Shoot(selected_gun));
                     } else {
                         None
@@ -176,46 +232,145 @@ impl Player {
                 .unwrap_or(Action::None),
         ))
     }
-    pub fn draw(&self, window: &mut Window, z: i32) {
-        let mut player_rec = self.get_rectangle();
-        window.draw_ex(
-            &player_rec,
-            Col(if self.invis_timer == 0 {
-                Color::WHITE
-            } else {
-                Color::ORANGE
-            }),
-            Transform::IDENTITY,
-            z,
-        );
-        player_rec.pos.y += 20.;
-        player_rec.size.y = 15.;
-        player_rec.size.x = 20.;
-        window.draw_ex(
-            &player_rec,
-            Img(&self.rendered_health),
-            Transform::IDENTITY,
-            z,
+    pub fn draw(
+        &self,
+        window: &mut Window,
+        z: i32,
+        camera: &Camera,
+        colors: &Colors,
+        tileset: &Tileset,
+        glitch: f32,
+    ) {
+        let player_rec = self.get_rectangle(camera);
+        let sprite = tileset.get(
+            &self
+                .animation
+                .sprite_name(player_prefix(Dir::from_angle(self.aim))),
         );
+        let flashing = !self.hurt.is_finished() && self.hurt.frame().is_multiple_of(2);
+        let background = match (sprite, !flashing) {
+            (Some(sprite), true) => Img(sprite),
+            (Some(sprite), false) => Blended(sprite, colors.player_hurt),
+            (None, true) => Col(colors.player),
+            (None, false) => Col(colors.player_hurt),
+        };
+        if let Some(sprite) = sprite {
+            draw_channel_split(window, &player_rec, sprite, glitch, z);
+        }
+        window.draw_ex(&player_rec, background, Transform::IDENTITY, z);
     }
-    pub fn get_rectangle(&self) -> Rectangle {
-        let player_on_screen = self.grid_to_screen(&(
-            self.location.location.x / CELL_SIZE as f32,
-            self.location.location.y / CELL_SIZE as f32,
-        ));
-        Rectangle::new(player_on_screen, (PLAYER_SIZE as i32, PLAYER_SIZE as i32))
-            .with_center(player_on_screen)
-    }
-    pub fn grid_to_screen(&self, loc: &(f32, f32)) -> (f32, f32) {
-        let cell_size = CELL_SIZE as f32;
-        let width = 800. / cell_size;
-        let height = 600. / cell_size;
-
-        let x =
-            (loc.0 as f32 - (self.location.location.x / cell_size - width / 2.)) * cell_size as f32;
-        let y = (loc.1 as f32 - (self.location.location.y / cell_size - height / 2.))
-            * cell_size as f32;
-        (x, y)
+    pub fn health_label(&self, camera: &Camera) -> Rectangle {
+        camera.label_below(&self.get_rectangle(camera))
+    }
+    pub fn max_health(&self) -> isize {
+        self.max_health.value().round() as isize
+    }
+    pub fn move_speed(&self) -> f32 {
+        // Stacked boosts must not carry a step past a whole wall cell.
+        self.speed.value().min((CELL_SIZE - PLAYER_SIZE) as f32)
+    }
+    pub fn cooldown(&self, gun: &Gun) -> usize {
+        ((gun.cooldown() as f32 * self.cooldown_scale.value()).round() as usize).max(2)
+    }
+    pub fn max_guns(&self) -> usize {
+        self.gun_slots.value().round() as usize
+    }
+    pub fn gain_xp(&mut self, amount: u64) -> bool {
+        self.xp += amount;
+        let mut leveled = false;
+        while self.xp >= xp_for_level(self.level) {
+            self.xp -= xp_for_level(self.level);
+            self.level += 1;
+            self.pending_levels += 1;
+            leveled = true;
+        }
+        leveled
+    }
+    pub fn apply_upgrade(&mut self, upgrade: Upgrade) {
+        self.upgrades.push(upgrade);
+        match upgrade {
+            Upgrade::MaxHealth => {
+                self.max_health.add(Source::Level, Modifier::Add(20.));
+                self.health += 20;
+            }
+            Upgrade::MoveSpeed => {
+                self.speed.add(Source::Level, Modifier::Multiply(1.1));
+            }
+            Upgrade::Cooldown => {
+                self.cooldown_scale
+                    .add(Source::Level, Modifier::Multiply(0.85));
+            }
+            Upgrade::GunSlot => {
+                self.gun_slots.add(Source::Level, Modifier::Add(1.));
+            }
+        }
+        self.pending_levels = self.pending_levels.saturating_sub(1);
+    }
+    pub fn shot_aim(&self) -> f32 {
+        match self
+            .status
+            .active
+            .iter()
+            .find(|v| v.kind == StatusKind::Corrupt)
+        {
+            Some(corrupt) => {
+                let jitter = 8. * corrupt.stacks as f32;
+                self.aim + rand::thread_rng().gen_range(-jitter, jitter)
+            }
+            None => self.aim,
+        }
+    }
+    pub fn add_gun(&mut self, gun: Gun) {
+        self.guns.push(gun);
+        if self.guns.len() > self.max_guns() {
+            self.guns.remove(0);
+        }
+        self.selected_gun = self.selected_gun.min(self.guns.len() - 1);
+    }
+    pub fn use_item(&mut self) -> Option<ItemEffect> {
+        if self.items.is_empty() {
+            return None;
+        }
+        let effect = self.items.remove(0).effect;
+        match effect {
+            ItemEffect::Heal(amount) => self.health = (self.health + amount).min(self.max_health()),
+            ItemEffect::Shield(amount) => self.shield += amount,
+            ItemEffect::Speed { percent, duration } => {
+                if let Some(boost) = self.speed_boost.take() {
+                    self.speed.remove_modifier(boost);
+                }
+                self.speed_boost = Some(
+                    self.speed
+                        .add(Source::Item, Modifier::Multiply(1. + percent as f32 / 100.)),
+                );
+                self.speed_timer = duration;
+            }
+            ItemEffect::Bomb(_) => {}
+        }
+        Some(effect)
+    }
+    pub fn take_damage(&mut self, amount: isize) {
+        self.take_tick_damage(amount);
+        self.take_hit();
+    }
+    // Status ticks go through the shield but do not restart the hurt timer.
+    pub fn take_tick_damage(&mut self, amount: isize) -> isize {
+        let absorbed = amount.min(self.shield).max(0);
+        self.shield -= absorbed;
+        self.health -= amount - absorbed;
+        amount - absorbed
+    }
+    pub fn is_invulnerable(&self) -> bool {
+        !self.hurt.is_finished()
+    }
+    pub fn take_hit(&mut self) {
+        self.hurt.play(HURT);
+    }
+    pub fn get_rectangle(&self, camera: &Camera) -> Rectangle {
+        camera.rectangle(
+            self.location.location,
+            Vector::new(PLAYER_SIZE as f32, PLAYER_SIZE as f32),
+        )
     }
 }
 
@@ -0,0 +1,43 @@
+use crate::config::Colors;
+use quicksilver::graphics::Color;
+
+pub const HEAL_CAP: isize = 12;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Polarity {
+    Positive,
+    Negative,
+}
+impl Polarity {
+    pub fn of(value: isize) -> Polarity {
+        if value < 0 {
+            Polarity::Negative
+        } else {
+            Polarity::Positive
+        }
+    }
+    pub fn flipped(self) -> Polarity {
+        match self {
+            Polarity::Positive => Polarity::Negative,
+            Polarity::Negative => Polarity::Positive,
+        }
+    }
+    pub fn signed(self, amount: isize) -> isize {
+        match self {
+            Polarity::Positive => amount,
+            Polarity::Negative => -amount,
+        }
+    }
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Polarity::Positive => "+",
+            Polarity::Negative => "-",
+        }
+    }
+    pub fn color(self, colors: &Colors) -> Color {
+        match self {
+            Polarity::Positive => colors.bullet_positive,
+            Polarity::Negative => colors.bullet_negative,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use rand::seq::SliceRandom;
+
+pub const XP_PER_KILL: u64 = 5;
+pub const CHOICES: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Upgrade {
+    MaxHealth,
+    MoveSpeed,
+    Cooldown,
+    GunSlot,
+}
+impl Upgrade {
+    pub const ALL: [Upgrade; 4] = [
+        Upgrade::MaxHealth,
+        Upgrade::MoveSpeed,
+        Upgrade::Cooldown,
+        Upgrade::GunSlot,
+    ];
+    pub fn label(self) -> &'static str {
+        match self {
+            Upgrade::MaxHealth => "+20 max health",
+            Upgrade::MoveSpeed => "+10% move speed",
+            Upgrade::Cooldown => "-15% gun cooldown",
+            Upgrade::GunSlot => "+1 gun slot",
+        }
+    }
+    pub fn choices() -> Vec<Upgrade> {
+        Upgrade::ALL
+            .choose_multiple(&mut rand::thread_rng(), CHOICES)
+            .copied()
+            .collect()
+    }
+}
+
+pub fn xp_for_level(level: usize) -> u64 {
+    20 + level as u64 * 15
+}
@@ -0,0 +1,108 @@
+use super::menu::Menu;
+use super::screen::{Screen, Transition};
+use crate::config::Config;
+use crate::controls::{key_name, Control, ALL_CONTROLS, BINDABLE_KEYS};
+use quicksilver::geom::Rectangle;
+use quicksilver::graphics::Color;
+use quicksilver::graphics::Font;
+use quicksilver::graphics::FontStyle;
+use quicksilver::input::ButtonState;
+use quicksilver::input::Key;
+use quicksilver::lifecycle::Window;
+use quicksilver::prelude::Col;
+use quicksilver::Result;
+
+fn binding_text(config: &Config, control: Control) -> String {
+    let keys: Vec<_> = config
+        .controls
+        .keys
+        .get(&control)
+        .map(|keys| keys.iter().map(|v| key_name(*v)).collect())
+        .unwrap_or_default();
+    format!("{}: {}", control.name(), keys.join(", "))
+}
+
+pub struct BindingsScreen {
+    menu: Menu,
+    waiting_for: Option<Control>,
+}
+
+impl BindingsScreen {
+    pub fn new(config: &Config, font: &Font, style: &FontStyle) -> Result<Self> {
+        let mut options: Vec<_> = ALL_CONTROLS
+            .iter()
+            .map(|v| binding_text(config, *v))
+            .collect();
+        options.push(String::from("Back"));
+        let options: Vec<_> = options.iter().map(String::as_str).collect();
+        let mut menu = Menu::new(&options, font, style)?;
+        menu.scale = 0.3;
+        Ok(Self {
+            menu,
+            waiting_for: None,
+        })
+    }
+}
+
+impl Screen for BindingsScreen {
+    fn update(
+        &mut self,
+        window: &mut Window,
+        config: &mut Config,
+        font: &Font,
+        style: &FontStyle,
+    ) -> Result<Option<Transition>> {
+        if let Some(control) = self.waiting_for {
+            let board = window.keyboard();
+            if board[Key::Escape] == ButtonState::Pressed {
+                self.waiting_for = None;
+                self.menu.set_option(
+                    self.menu.selected,
+                    &binding_text(config, control),
+                    font,
+                    style,
+                )?;
+                return Ok(None);
+            }
+            let pressed = BINDABLE_KEYS
+                .iter()
+                .find(|key| board[**key] == ButtonState::Pressed);
+            if let Some(key) = pressed {
+                config.controls.keys.insert(control, vec![*key]);
+                self.waiting_for = None;
+                self.menu.set_option(
+                    self.menu.selected,
+                    &binding_text(config, control),
+                    font,
+                    style,
+                )?;
+            }
+            return Ok(None);
+        }
+        if config.controls.is_pressed(window, Control::Pause) {
+            return Ok(Some(Transition::Pop));
+        }
+        match self.menu.update(window, &config.controls) {
+            Some(index) if index < ALL_CONTROLS.len() => {
+                let control = ALL_CONTROLS[index];
+                self.waiting_for = Some(control);
+                self.menu.set_option(
+                    index,
+                    &format!("{}: press a key (Escape cancels)", control.name()),
+                    font,
+                    style,
+                )?;
+                Ok(None)
+            }
+            Some(_) => Ok(Some(Transition::Pop)),
+            None => Ok(None),
+        }
+    }
+
+    fn draw(&self, window: &mut Window, _: &Config, _: &Font, _: &FontStyle) -> Result<()> {
+        let size = window.screen_size();
+        window.draw(&Rectangle::new_sized(size), Col(Color::BLACK));
+        self.menu.draw(window, size / 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use super::menu::{Menu, TEXT_SCALE};
+use super::screen::{Screen, Transition};
+use crate::config::Config;
+use crate::controls::Control;
+use quicksilver::geom::Rectangle;
+use quicksilver::geom::Shape;
+use quicksilver::geom::Transform;
+use quicksilver::graphics::Color;
+use quicksilver::graphics::Font;
+use quicksilver::graphics::FontStyle;
+use quicksilver::graphics::Image;
+use quicksilver::lifecycle::Window;
+use quicksilver::prelude::{Col, Img};
+use quicksilver::Result;
+
+pub type OnConfirm = Box<dyn Fn(&Font, &FontStyle) -> Result<Transition>>;
+
+pub struct ConfirmScreen {
+    rendered_question: Image,
+    menu: Menu,
+    on_confirm: OnConfirm,
+}
+
+impl ConfirmScreen {
+    pub fn new(
+        question: &str,
+        on_confirm: OnConfirm,
+        font: &Font,
+        style: &FontStyle,
+    ) -> Result<Self> {
+        let rendered_question = font.render(question, style)?;
+        let mut menu = Menu::new(&["Yes", "No"], font, style)?;
+        menu.selected = 1;
+        Ok(Self {
+            rendered_question,
+            menu,
+            on_confirm,
+        })
+    }
+}
+
+impl Screen for ConfirmScreen {
+    fn update(
+        &mut self,
+        window: &mut Window,
+        config: &mut Config,
+        font: &Font,
+        style: &FontStyle,
+    ) -> Result<Option<Transition>> {
+        if config.controls.is_pressed(window, Control::Pause) {
+            return Ok(Some(Transition::Pop));
+        }
+        match self.menu.update(window, &config.controls) {
+            Some(0) => Ok(Some((self.on_confirm)(font, style)?)),
+            Some(_) => Ok(Some(Transition::Pop)),
+            None => Ok(None),
+        }
+    }
+
+    fn draw(&self, window: &mut Window, _: &Config, _: &Font, _: &FontStyle) -> Result<()> {
+        let size = window.screen_size();
+        window.draw(
+            &Rectangle::new_sized(size),
+            Col(Color::BLACK.with_alpha(0.6)),
+        );
+        let question_size = self.rendered_question.area().size() * TEXT_SCALE;
+        window.draw_ex(
+            &Rectangle::new((0, 0), question_size).with_center((size.x / 2., size.y / 3.)),
+            Img(&self.rendered_question),
+            Transform::IDENTITY,
+            2,
+        );
+        self.menu.draw(window, size / 2);
+        Ok(())
+    }
+
+    fn is_transparent(&self) -> bool {
+        true
+    }
+}
@@ -1,13 +1,15 @@
 use super::in_game::InGameScreen;
-use super::screen::Screen;
+use super::screen::{Screen, Transition};
+use crate::config::Config;
+use crate::controls::Control;
 
-use crate::player::check_multiple_pressed;
 use quicksilver::geom::Rectangle;
+use quicksilver::geom::Shape;
 use quicksilver::geom::Transform;
+use quicksilver::geom::Vector;
 use quicksilver::graphics::Font;
 use quicksilver::graphics::FontStyle;
 use quicksilver::graphics::Image;
-use quicksilver::input::Key;
 use quicksilver::lifecycle::Window;
 use quicksilver::prelude::Img;
 use quicksilver::Result;
@@ -33,24 +35,27 @@ impl Screen for DeadScreen {
     fn update(
         &mut self,
         window: &mut Window,
-        font: &Font,
-        style: &FontStyle,
-    ) -> Result<Option<Box<dyn Screen>>> {
-        let board = window.keyboard();
-        if check_multiple_pressed(&board, &[Key::Escape, Key::Return]) {
-            return Ok(Some(Box::new(InGameScreen::new(font, style)?)));
+        config: &mut Config,
+        _: &Font,
+        _: &FontStyle,
+    ) -> Result<Option<Transition>> {
+        if config.controls.is_pressed(window, Control::Confirm) {
+            return Ok(Some(Transition::Fade(Box::new(Transition::Replace(
+                Box::new(InGameScreen::new(window.screen_size())?),
+            )))));
         }
         Ok(None)
     }
-    fn draw(&self, window: &mut Window, _: &Font, _: &FontStyle) -> Result<()> {
+    fn draw(&self, window: &mut Window, _: &Config, _: &Font, _: &FontStyle) -> Result<()> {
+        let center = window.screen_size() / 2;
         window.draw_ex(
-            &Rectangle::new((200, 150), (380, 200)),
+            &Rectangle::new((0, 0), (380, 200)).with_center(center - Vector::new(10, 50)),
             Img(&self.rendered_dead_text),
             Transform::IDENTITY,
             2,
         );
         window.draw_ex(
-            &Rectangle::new((350, 350), (100, 100)),
+            &Rectangle::new((0, 0), (100, 100)).with_center(center + Vector::new(0, 100)),
             Img(&self.rendered_score),
             Transform::IDENTITY,
             1,
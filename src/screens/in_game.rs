@@ -1,44 +1,74 @@
-use super::screen::Screen;
+use super::level_up::LevelUpScreen;
+use super::pause::PauseScreen;
+use super::screen::{Screen, Transition};
+use super::shop::ShopScreen;
+use crate::config::Config;
+use crate::controls::Control;
 use crate::game_state::GameState;
+use quicksilver::geom::Vector;
 use quicksilver::graphics::Font;
 use quicksilver::graphics::FontStyle;
 use quicksilver::lifecycle::Window;
 use quicksilver::Result;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 use super::DeadScreen;
 use crate::game_state::StateAction;
 pub struct InGameScreen {
-    state: GameState,
+    state: Rc<RefCell<GameState>>,
 }
 impl Screen for InGameScreen {
     fn update(
         &mut self,
         window: &mut Window,
+        config: &mut Config,
         font: &Font,
         style: &FontStyle,
-    ) -> Result<Option<Box<dyn Screen>>> {
-        match self.state.update(window, font, style)? {
+    ) -> Result<Option<Transition>> {
+        if config.controls.is_pressed(window, Control::Pause) {
+            return Ok(Some(Transition::Push(Box::new(PauseScreen::new(
+                font, style,
+            )?))));
+        }
+        let action = self.state.borrow_mut().update(window, &config.controls)?;
+        match action {
             StateAction::NextLevel => {
-                self.state.reset(font, style)?;
-                Ok(None)
+                self.state.borrow_mut().reset()?;
+                Ok(Some(Transition::Fade(Box::new(Transition::Push(
+                    Box::new(ShopScreen::new(Rc::clone(&self.state), font, style)?),
+                )))))
             }
-            StateAction::Die => Ok(Some(Box::new(DeadScreen::new(
-                self.state.score,
+            StateAction::LevelUp => Ok(Some(Transition::Push(Box::new(LevelUpScreen::new(
+                Rc::clone(&self.state),
                 font,
                 style,
-            )?))),
+            )?)))),
+            StateAction::Die => Ok(Some(Transition::Fade(Box::new(Transition::Replace(
+                Box::new(DeadScreen::new(self.state.borrow().score, font, style)?),
+            ))))),
             StateAction::None => Ok(None),
         }
     }
 
-    fn draw(&self, window: &mut Window, _: &Font, _: &FontStyle) -> Result<()> {
-        self.state.draw(window);
+    fn draw(
+        &self,
+        window: &mut Window,
+        config: &Config,
+        font: &Font,
+        style: &FontStyle,
+    ) -> Result<()> {
+        let state = self.state.borrow();
+        state.draw(window, config, font, style)?;
+        window.flush()?;
+        state.draw_map(window, config);
+        state.draw_hud(window, config, font, style)?;
         Ok(())
     }
 }
 impl InGameScreen {
-    pub fn new(font: &Font, style: &FontStyle) -> Result<Self> {
-        let state = GameState::new(font, style)?;
+    pub fn new(viewport: Vector) -> Result<Self> {
+        let state = Rc::new(RefCell::new(GameState::new(viewport)?));
         Ok(Self { state })
     }
 }
@@ -0,0 +1,95 @@
+use super::menu::{Menu, TEXT_SCALE};
+use super::pause::PauseScreen;
+use super::screen::{Screen, Transition};
+use crate::config::Config;
+use crate::controls::Control;
+use crate::game_state::GameState;
+use crate::progression::Upgrade;
+use quicksilver::geom::Rectangle;
+use quicksilver::geom::Shape;
+use quicksilver::geom::Transform;
+use quicksilver::graphics::Color;
+use quicksilver::graphics::Font;
+use quicksilver::graphics::FontStyle;
+use quicksilver::graphics::Image;
+use quicksilver::lifecycle::Window;
+use quicksilver::prelude::{Col, Img};
+use quicksilver::Result;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub struct LevelUpScreen {
+    state: Rc<RefCell<GameState>>,
+    choices: Vec<Upgrade>,
+    menu: Menu,
+    rendered_title: Image,
+}
+
+impl LevelUpScreen {
+    pub fn new(state: Rc<RefCell<GameState>>, font: &Font, style: &FontStyle) -> Result<Self> {
+        let level = state.borrow().player.level;
+        let choices = Upgrade::choices();
+        let options: Vec<_> = choices.iter().map(|v| v.label()).collect();
+        Ok(Self {
+            rendered_title: font.render(&format!("Level {} - choose an upgrade", level), style)?,
+            menu: Menu::new(&options, font, style)?,
+            choices,
+            state,
+        })
+    }
+}
+
+impl Screen for LevelUpScreen {
+    fn update(
+        &mut self,
+        window: &mut Window,
+        config: &mut Config,
+        font: &Font,
+        style: &FontStyle,
+    ) -> Result<Option<Transition>> {
+        if config.controls.is_pressed(window, Control::Pause) {
+            return Ok(Some(Transition::Push(Box::new(PauseScreen::new(
+                font, style,
+            )?))));
+        }
+        let upgrade = match self.menu.update(window, &config.controls) {
+            Some(index) => self.choices[index],
+            None => return Ok(None),
+        };
+        let pending = {
+            let mut state = self.state.borrow_mut();
+            state.player.apply_upgrade(upgrade);
+            state.player.pending_levels
+        };
+        if pending > 0 {
+            Ok(Some(Transition::Replace(Box::new(LevelUpScreen::new(
+                Rc::clone(&self.state),
+                font,
+                style,
+            )?))))
+        } else {
+            Ok(Some(Transition::Pop))
+        }
+    }
+
+    fn draw(&self, window: &mut Window, _: &Config, _: &Font, _: &FontStyle) -> Result<()> {
+        let size = window.screen_size();
+        window.draw(
+            &Rectangle::new_sized(size),
+            Col(Color::BLACK.with_alpha(0.6)),
+        );
+        let title_size = self.rendered_title.area().size() * TEXT_SCALE;
+        window.draw_ex(
+            &Rectangle::new((0, 0), title_size).with_center((size.x / 2., size.y / 3.)),
+            Img(&self.rendered_title),
+            Transform::IDENTITY,
+            2,
+        );
+        self.menu.draw(window, size / 2);
+        Ok(())
+    }
+
+    fn is_transparent(&self) -> bool {
+        true
+    }
+}
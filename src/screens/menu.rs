@@ -0,0 +1,85 @@
+use crate::controls::{Control, Controls};
+use quicksilver::geom::Rectangle;
+use quicksilver::geom::Shape;
+use quicksilver::geom::Transform;
+use quicksilver::geom::Vector;
+use quicksilver::graphics::Color;
+use quicksilver::graphics::Font;
+use quicksilver::graphics::FontStyle;
+use quicksilver::graphics::Image;
+use quicksilver::lifecycle::Window;
+use quicksilver::prelude::{Col, Img};
+use quicksilver::Result;
+
+pub const TEXT_SCALE: f32 = 0.4;
+
+pub struct Menu {
+    rendered_options: Vec<Image>,
+    pub selected: usize,
+    pub scale: f32,
+}
+impl Menu {
+    pub fn new(options: &[&str], font: &Font, style: &FontStyle) -> Result<Self> {
+        let mut rendered_options = Vec::new();
+        for option in options {
+            rendered_options.push(font.render(option, style)?);
+        }
+        Ok(Self {
+            rendered_options,
+            selected: 0,
+            scale: TEXT_SCALE,
+        })
+    }
+    pub fn set_option(
+        &mut self,
+        index: usize,
+        option: &str,
+        font: &Font,
+        style: &FontStyle,
+    ) -> Result<()> {
+        if let Some(rendered) = self.rendered_options.get_mut(index) {
+            *rendered = font.render(option, style)?;
+        }
+        Ok(())
+    }
+    pub fn update(&mut self, window: &Window, controls: &Controls) -> Option<usize> {
+        let amount = self.rendered_options.len();
+        if controls.is_pressed(window, Control::MoveUp)
+            || controls.is_pressed(window, Control::AimUp)
+        {
+            self.selected = (self.selected + amount - 1) % amount;
+        }
+        if controls.is_pressed(window, Control::MoveDown)
+            || controls.is_pressed(window, Control::AimDown)
+        {
+            self.selected = (self.selected + 1) % amount;
+        }
+        if controls.is_pressed(window, Control::Confirm) {
+            Some(self.selected)
+        } else {
+            None
+        }
+    }
+    pub fn draw(&self, window: &mut Window, center: Vector) {
+        let sizes: Vec<_> = self
+            .rendered_options
+            .iter()
+            .map(|v| v.area().size() * self.scale)
+            .collect();
+        let total_height: f32 = sizes.iter().map(|v| v.y).sum();
+        let mut y = center.y - total_height / 2.;
+        for (key, (option, size)) in self.rendered_options.iter().zip(sizes).enumerate() {
+            let rec = Rectangle::new((0, 0), size).with_center((center.x, y + size.y / 2.));
+            if key == self.selected {
+                window.draw_ex(
+                    &Rectangle::new(rec.pos - Vector::new(10, 0), rec.size + Vector::new(20, 0)),
+                    Col(Color::from_rgba(128, 64, 128, 1.)),
+                    Transform::IDENTITY,
+                    1,
+                );
+            }
+            window.draw_ex(&rec, Img(option), Transform::IDENTITY, 2);
+            y += size.y;
+        }
+    }
+}
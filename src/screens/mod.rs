@@ -1,6 +1,14 @@
+pub mod bindings;
+pub mod confirm;
 pub mod dead;
 pub mod in_game;
+pub mod level_up;
+pub mod menu;
+pub mod pause;
 pub mod screen;
+pub mod settings;
+pub mod shop;
+pub mod stack;
 pub mod start;
 
 pub use dead::DeadScreen;
@@ -0,0 +1,86 @@
+use super::confirm::ConfirmScreen;
+use super::in_game::InGameScreen;
+use super::menu::Menu;
+use super::screen::{Screen, Transition};
+use super::settings::SettingsScreen;
+use super::start::StartScreen;
+use crate::config::Config;
+use crate::controls::Control;
+use quicksilver::geom::Rectangle;
+use quicksilver::graphics::Color;
+use quicksilver::graphics::Font;
+use quicksilver::graphics::FontStyle;
+use quicksilver::lifecycle::Window;
+use quicksilver::prelude::Col;
+use quicksilver::Result;
+
+pub struct PauseScreen {
+    menu: Menu,
+}
+
+impl PauseScreen {
+    pub fn new(font: &Font, style: &FontStyle) -> Result<Self> {
+        let menu = Menu::new(
+            &["Resume", "Restart run", "Settings", "Quit to title"],
+            font,
+            style,
+        )?;
+        Ok(Self { menu })
+    }
+}
+
+impl Screen for PauseScreen {
+    fn update(
+        &mut self,
+        window: &mut Window,
+        config: &mut Config,
+        font: &Font,
+        style: &FontStyle,
+    ) -> Result<Option<Transition>> {
+        if config.controls.is_pressed(window, Control::Pause) {
+            return Ok(Some(Transition::Pop));
+        }
+        let viewport = window.screen_size();
+        Ok(match self.menu.update(window, &config.controls) {
+            Some(0) => Some(Transition::Pop),
+            Some(1) => Some(Transition::Push(Box::new(ConfirmScreen::new(
+                "Restart this run?",
+                Box::new(move |_, _| {
+                    Ok(Transition::Fade(Box::new(Transition::ReplaceAll(
+                        Box::new(InGameScreen::new(viewport)?),
+                    ))))
+                }),
+                font,
+                style,
+            )?))),
+            Some(2) => Some(Transition::Push(Box::new(SettingsScreen::new(
+                config, font, style,
+            )?))),
+            Some(_) => Some(Transition::Push(Box::new(ConfirmScreen::new(
+                "Quit to title?",
+                Box::new(|font, style| {
+                    Ok(Transition::Fade(Box::new(Transition::ReplaceAll(
+                        Box::new(StartScreen::new(font, style)?),
+                    ))))
+                }),
+                font,
+                style,
+            )?))),
+            None => None,
+        })
+    }
+
+    fn draw(&self, window: &mut Window, _: &Config, _: &Font, _: &FontStyle) -> Result<()> {
+        let size = window.screen_size();
+        window.draw(
+            &Rectangle::new_sized(size),
+            Col(Color::BLACK.with_alpha(0.6)),
+        );
+        self.menu.draw(window, size / 2);
+        Ok(())
+    }
+
+    fn is_transparent(&self) -> bool {
+        true
+    }
+}
@@ -1,14 +1,35 @@
+use crate::config::Config;
 use quicksilver::graphics::Font;
 use quicksilver::graphics::FontStyle;
 use quicksilver::lifecycle::Window;
 use quicksilver::Result;
+
+pub enum Transition {
+    Push(Box<dyn Screen>),
+    Pop,
+    Replace(Box<dyn Screen>),
+    ReplaceAll(Box<dyn Screen>),
+    Fade(Box<Transition>),
+}
+
 pub trait Screen {
     fn update(
         &mut self,
         window: &mut Window,
+        config: &mut Config,
+        font: &Font,
+        style: &FontStyle,
+    ) -> Result<Option<Transition>>;
+
+    fn draw(
+        &self,
+        window: &mut Window,
+        config: &Config,
         font: &Font,
         style: &FontStyle,
-    ) -> Result<Option<Box<dyn Screen>>>;
+    ) -> Result<()>;
 
-    fn draw(&self, window: &mut Window, font: &Font, style: &FontStyle) -> Result<()>;
+    fn is_transparent(&self) -> bool {
+        false
+    }
 }
@@ -0,0 +1,152 @@
+use super::bindings::BindingsScreen;
+use super::menu::Menu;
+use super::screen::{Screen, Transition};
+use crate::config::{Config, RESOLUTIONS};
+use crate::controls::Control;
+use quicksilver::geom::Rectangle;
+use quicksilver::graphics::Color;
+use quicksilver::graphics::Font;
+use quicksilver::graphics::FontStyle;
+use quicksilver::lifecycle::Window;
+use quicksilver::prelude::Col;
+use quicksilver::Result;
+
+const RESOLUTION: usize = 0;
+const FULLSCREEN: usize = 1;
+const FONT_SCALE: usize = 2;
+const PALETTE: usize = 3;
+const GLITCH: usize = 4;
+const KEY_BINDINGS: usize = 5;
+const SAVE: usize = 6;
+const CANCEL: usize = 7;
+
+fn option_text(config: &Config, option: usize) -> String {
+    match option {
+        RESOLUTION => format!("Resolution: {}x{}", config.width, config.height),
+        FULLSCREEN => format!(
+            "Fullscreen: {}",
+            if config.fullscreen { "on" } else { "off" }
+        ),
+        FONT_SCALE => format!("Font scale: {:.1}", config.font_scale),
+        PALETTE => format!("Palette: {}", config.palette.name()),
+        GLITCH if config.glitch_intensity == 0. => String::from("Glitch effects: off"),
+        GLITCH => format!(
+            "Glitch effects: {}%",
+            (config.glitch_intensity * 100.).round()
+        ),
+        KEY_BINDINGS => String::from("Key bindings"),
+        SAVE => String::from("Save and back"),
+        _ => String::from("Cancel"),
+    }
+}
+
+pub struct SettingsScreen {
+    menu: Menu,
+    original: Config,
+}
+
+impl SettingsScreen {
+    pub fn new(config: &Config, font: &Font, style: &FontStyle) -> Result<Self> {
+        let options: Vec<_> = (RESOLUTION..=CANCEL)
+            .map(|v| option_text(config, v))
+            .collect();
+        let options: Vec<_> = options.iter().map(String::as_str).collect();
+        let menu = Menu::new(&options, font, style)?;
+        Ok(Self {
+            menu,
+            original: config.clone(),
+        })
+    }
+    fn change(&mut self, config: &mut Config, window: &mut Window, step: isize) {
+        match self.menu.selected {
+            RESOLUTION => {
+                let current = RESOLUTIONS
+                    .iter()
+                    .position(|v| *v == (config.width, config.height))
+                    .unwrap_or(0) as isize;
+                let amount = RESOLUTIONS.len() as isize;
+                let next = RESOLUTIONS[((current + step + amount) % amount) as usize];
+                config.width = next.0;
+                config.height = next.1;
+                config.apply(window);
+            }
+            FULLSCREEN => {
+                config.fullscreen = !config.fullscreen;
+                config.apply(window);
+            }
+            FONT_SCALE => {
+                config.font_scale = (config.font_scale + step as f32 * 0.1).clamp(0.5, 2.);
+            }
+            PALETTE => config.palette = config.palette.next(),
+            GLITCH => {
+                config.glitch_intensity =
+                    (config.glitch_intensity + step as f32 * 0.25).clamp(0., 1.);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Screen for SettingsScreen {
+    fn update(
+        &mut self,
+        window: &mut Window,
+        config: &mut Config,
+        font: &Font,
+        style: &FontStyle,
+    ) -> Result<Option<Transition>> {
+        if config.controls.is_pressed(window, Control::Pause) {
+            *config = self.original.clone();
+            config.apply(window);
+            return Ok(Some(Transition::Pop));
+        }
+        let mut step = 0;
+        if config.controls.is_pressed(window, Control::MoveLeft)
+            || config.controls.is_pressed(window, Control::AimLeft)
+        {
+            step = -1;
+        }
+        if config.controls.is_pressed(window, Control::MoveRight)
+            || config.controls.is_pressed(window, Control::AimRight)
+        {
+            step = 1;
+        }
+        match self.menu.update(window, &config.controls) {
+            Some(KEY_BINDINGS) => {
+                return Ok(Some(Transition::Push(Box::new(BindingsScreen::new(
+                    config, font, style,
+                )?))));
+            }
+            Some(SAVE) => {
+                // Stay on the screen so a failed save can be retried or cancelled.
+                if config.save().is_err() {
+                    self.menu
+                        .set_option(SAVE, "Save failed - try again", font, style)?;
+                    return Ok(None);
+                }
+                return Ok(Some(Transition::Pop));
+            }
+            Some(CANCEL) => {
+                *config = self.original.clone();
+                config.apply(window);
+                return Ok(Some(Transition::Pop));
+            }
+            Some(_) => step = 1,
+            None => {}
+        }
+        if step != 0 {
+            let selected = self.menu.selected;
+            self.change(config, window, step);
+            self.menu
+                .set_option(selected, &option_text(config, selected), font, style)?;
+        }
+        Ok(None)
+    }
+
+    fn draw(&self, window: &mut Window, _: &Config, _: &Font, _: &FontStyle) -> Result<()> {
+        let size = window.screen_size();
+        window.draw(&Rectangle::new_sized(size), Col(Color::BLACK));
+        self.menu.draw(window, size / 2);
+        Ok(())
+    }
+}
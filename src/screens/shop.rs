@@ -0,0 +1,93 @@
+use super::menu::{Menu, TEXT_SCALE};
+use super::screen::{Screen, Transition};
+use crate::config::Config;
+use crate::controls::Control;
+use crate::game_state::GameState;
+use crate::shop::Shop;
+use quicksilver::geom::Rectangle;
+use quicksilver::geom::Shape;
+use quicksilver::geom::Transform;
+use quicksilver::graphics::Color;
+use quicksilver::graphics::Font;
+use quicksilver::graphics::FontStyle;
+use quicksilver::graphics::Image;
+use quicksilver::lifecycle::Window;
+use quicksilver::prelude::{Col, Img};
+use quicksilver::Result;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub struct ShopScreen {
+    state: Rc<RefCell<GameState>>,
+    shop: Shop,
+    menu: Menu,
+    rendered_title: Image,
+    rendered_coins: Image,
+}
+
+impl ShopScreen {
+    pub fn new(state: Rc<RefCell<GameState>>, font: &Font, style: &FontStyle) -> Result<Self> {
+        let (shop, coins) = {
+            let state = state.borrow();
+            (Shop::new(state.floor, &state.items), state.player.coins)
+        };
+        let mut options: Vec<_> = (0..shop.offers.len()).map(|v| shop.label(v)).collect();
+        options.push(String::from("Continue"));
+        let options: Vec<_> = options.iter().map(String::as_str).collect();
+        let menu = Menu::new(&options, font, style)?;
+        Ok(Self {
+            rendered_title: font.render(&format!("Shop - floor {}", shop.floor), style)?,
+            rendered_coins: font.render(&format!("Coins: {}", coins), style)?,
+            state,
+            shop,
+            menu,
+        })
+    }
+}
+
+impl Screen for ShopScreen {
+    fn update(
+        &mut self,
+        window: &mut Window,
+        config: &mut Config,
+        font: &Font,
+        style: &FontStyle,
+    ) -> Result<Option<Transition>> {
+        if config.controls.is_pressed(window, Control::Pause) {
+            return Ok(Some(Transition::Fade(Box::new(Transition::Pop))));
+        }
+        match self.menu.update(window, &config.controls) {
+            Some(index) if index < self.shop.offers.len() => {
+                let mut state = self.state.borrow_mut();
+                if self.shop.buy(index, &mut state.player) {
+                    self.menu
+                        .set_option(index, &self.shop.label(index), font, style)?;
+                    self.rendered_coins =
+                        font.render(&format!("Coins: {}", state.player.coins), style)?;
+                }
+                Ok(None)
+            }
+            Some(_) => Ok(Some(Transition::Fade(Box::new(Transition::Pop)))),
+            None => Ok(None),
+        }
+    }
+
+    fn draw(&self, window: &mut Window, _: &Config, _: &Font, _: &FontStyle) -> Result<()> {
+        let size = window.screen_size();
+        window.draw(&Rectangle::new_sized(size), Col(Color::BLACK));
+        for (image, y) in &[
+            (&self.rendered_title, size.y / 8.),
+            (&self.rendered_coins, size.y / 5.),
+        ] {
+            window.draw_ex(
+                &Rectangle::new((0, 0), image.area().size() * TEXT_SCALE)
+                    .with_center((size.x / 2., *y)),
+                Img(image),
+                Transform::IDENTITY,
+                2,
+            );
+        }
+        self.menu.draw(window, size / 2);
+        Ok(())
+    }
+}
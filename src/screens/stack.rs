@@ -0,0 +1,109 @@
+use super::screen::{Screen, Transition};
+use crate::config::Config;
+use quicksilver::geom::Rectangle;
+use quicksilver::graphics::Color;
+use quicksilver::graphics::Font;
+use quicksilver::graphics::FontStyle;
+use quicksilver::lifecycle::Window;
+use quicksilver::prelude::Col;
+use quicksilver::Result;
+
+const FADE_FRAMES: usize = 15;
+
+struct Fade {
+    pending: Option<Transition>,
+    frame: usize,
+}
+impl Fade {
+    fn alpha(&self) -> f32 {
+        let half = self.frame.min(FADE_FRAMES * 2 - self.frame) as f32;
+        half / FADE_FRAMES as f32
+    }
+}
+
+pub struct ScreenStack {
+    screens: Vec<Box<dyn Screen>>,
+    fade: Option<Fade>,
+}
+impl ScreenStack {
+    pub fn new(first: Box<dyn Screen>) -> Self {
+        Self {
+            screens: vec![first],
+            fade: None,
+        }
+    }
+    fn apply(&mut self, transition: Transition) {
+        match transition {
+            Transition::Push(screen) => self.screens.push(screen),
+            Transition::Pop if self.screens.len() > 1 => {
+                self.screens.pop();
+            }
+            Transition::Pop => {}
+            Transition::Replace(screen) => {
+                self.screens.pop();
+                self.screens.push(screen);
+            }
+            Transition::ReplaceAll(screen) => {
+                self.screens.clear();
+                self.screens.push(screen);
+            }
+            Transition::Fade(transition) => {
+                self.fade = Some(Fade {
+                    pending: Some(*transition),
+                    frame: 0,
+                })
+            }
+        }
+    }
+    pub fn update(
+        &mut self,
+        window: &mut Window,
+        config: &mut Config,
+        font: &Font,
+        style: &FontStyle,
+    ) -> Result<()> {
+        if let Some(fade) = &mut self.fade {
+            fade.frame += 1;
+            if fade.frame == FADE_FRAMES {
+                if let Some(transition) = fade.pending.take() {
+                    self.apply(transition);
+                }
+            } else if fade.frame >= FADE_FRAMES * 2 {
+                self.fade = None;
+            }
+            return Ok(());
+        }
+        let transition = match self.screens.last_mut() {
+            Some(screen) => screen.update(window, config, font, style)?,
+            None => None,
+        };
+        if let Some(transition) = transition {
+            self.apply(transition);
+        }
+        Ok(())
+    }
+    pub fn draw(
+        &self,
+        window: &mut Window,
+        config: &Config,
+        font: &Font,
+        style: &FontStyle,
+    ) -> Result<()> {
+        let first_visible = self
+            .screens
+            .iter()
+            .rposition(|screen| !screen.is_transparent())
+            .unwrap_or(0);
+        for screen in &self.screens[first_visible..] {
+            screen.draw(window, config, font, style)?;
+            window.flush()?;
+        }
+        if let Some(fade) = &self.fade {
+            window.draw(
+                &Rectangle::new_sized(window.screen_size()),
+                Col(Color::BLACK.with_alpha(fade.alpha())),
+            );
+        }
+        Ok(())
+    }
+}
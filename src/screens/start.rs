@@ -1,4 +1,6 @@
-use super::screen::Screen;
+use super::screen::{Screen, Transition};
+use crate::config::Config;
+use crate::controls::Control;
 use quicksilver::geom::Rectangle;
 use quicksilver::graphics::Font;
 use quicksilver::graphics::FontStyle;
@@ -8,16 +10,20 @@ use quicksilver::prelude::Img;
 use quicksilver::Result;
 
 use super::in_game::InGameScreen;
-use crate::player::check_multiple_pressed;
-use quicksilver::input::Key;
+use super::settings::SettingsScreen;
 pub struct StartScreen {
     rendered_main: Image,
+    rendered_settings_hint: Image,
 }
 
 impl StartScreen {
-    pub fn new() -> Result<Self> {
+    pub fn new(font: &Font, style: &FontStyle) -> Result<Self> {
         let rendered_main = Image::from_bytes(include_bytes!("../../static/start.png"))?;
-        Ok(Self { rendered_main })
+        let rendered_settings_hint = font.render("Tab: settings", style)?;
+        Ok(Self {
+            rendered_main,
+            rendered_settings_hint,
+        })
     }
 }
 
@@ -25,20 +31,30 @@ impl Screen for StartScreen {
     fn update(
         &mut self,
         window: &mut Window,
+        config: &mut Config,
         font: &Font,
         style: &FontStyle,
-    ) -> Result<Option<Box<dyn Screen>>> {
-        let board = window.keyboard();
-        if check_multiple_pressed(&board, &[Key::Escape, Key::Return]) {
-            return Ok(Some(Box::new(InGameScreen::new(font, style)?)));
+    ) -> Result<Option<Transition>> {
+        if config.controls.is_pressed(window, Control::Confirm) {
+            return Ok(Some(Transition::Fade(Box::new(Transition::Replace(
+                Box::new(InGameScreen::new(window.screen_size())?),
+            )))));
+        }
+        if config.controls.is_pressed(window, Control::Settings) {
+            return Ok(Some(Transition::Push(Box::new(SettingsScreen::new(
+                config, font, style,
+            )?))));
         }
         Ok(None)
     }
 
-    fn draw(&self, window: &mut Window, _: &Font, _: &FontStyle) -> Result<()> {
+    fn draw(&self, window: &mut Window, _: &Config, _: &Font, _: &FontStyle) -> Result<()> {
+        let size = window.screen_size();
+        window.draw(&Rectangle::new_sized(size), Img(&self.rendered_main));
+        let hint_size = self.rendered_settings_hint.area().size() * 0.25;
         window.draw(
-            &Rectangle::new((0, 0), (800, 600)),
-            Img(&self.rendered_main),
+            &Rectangle::new((10., size.y - 10. - hint_size.y), hint_size),
+            Img(&self.rendered_settings_hint),
         );
         Ok(())
     }
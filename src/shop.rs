@@ -0,0 +1,97 @@
+use crate::grid::Tile;
+use crate::gun::Gun;
+use crate::items::{Item, ItemRegistry};
+use crate::player::{Player, MAX_ITEMS};
+use crate::stats::{Modifier, Source};
+
+const HEAL_AMOUNT: isize = 40;
+const MIN_COOLDOWN: usize = 4;
+
+#[derive(Clone, Debug)]
+pub enum Offer {
+    Gun(Gun),
+    Heal(isize),
+    Item(Item),
+    TuneGun,
+}
+impl Offer {
+    fn base_price(&self) -> u64 {
+        match self {
+            Offer::Gun(_) => 30,
+            Offer::Heal(_) => 15,
+            Offer::Item(_) => 20,
+            Offer::TuneGun => 25,
+        }
+    }
+    pub fn label(&self) -> String {
+        match self {
+            Offer::Gun(gun) => format!("Gun {} ({})", gun.name, gun.polarity().symbol()),
+            Offer::Heal(amount) => format!("Heal +{}", amount),
+            Offer::Item(item) => item.name.clone(),
+            Offer::TuneGun => String::from("Tune current gun"),
+        }
+    }
+}
+
+pub fn price(base: u64, floor: usize) -> u64 {
+    base + base * floor.saturating_sub(1) as u64 / 2
+}
+
+pub struct Shop {
+    pub floor: usize,
+    pub offers: Vec<Option<(Offer, u64)>>,
+}
+impl Shop {
+    pub fn new(floor: usize, items: &ItemRegistry) -> Self {
+        let mut offers = vec![Offer::Gun(Tile::random_gun()), Offer::Heal(HEAL_AMOUNT)];
+        offers.extend(items.random().map(Offer::Item));
+        offers.push(Offer::TuneGun);
+        Self {
+            floor,
+            offers: offers
+                .into_iter()
+                .map(|offer| {
+                    let cost = price(offer.base_price(), floor);
+                    Some((offer, cost))
+                })
+                .collect(),
+        }
+    }
+    pub fn label(&self, index: usize) -> String {
+        match self.offers.get(index) {
+            Some(Some((offer, cost))) => format!("{} - {} coins", offer.label(), cost),
+            _ => String::from("Sold"),
+        }
+    }
+    pub fn buy(&mut self, index: usize, player: &mut Player) -> bool {
+        let (offer, cost) = match self.offers.get(index) {
+            Some(Some((offer, cost))) if *cost <= player.coins => (offer.clone(), *cost),
+            _ => return false,
+        };
+        match offer {
+            Offer::Gun(gun) => player.add_gun(gun),
+            Offer::Heal(amount) => {
+                if player.health >= player.max_health() {
+                    return false;
+                }
+                player.health = (player.health + amount).min(player.max_health());
+            }
+            Offer::Item(item) => {
+                if player.items.len() >= MAX_ITEMS {
+                    return false;
+                }
+                player.items.push(item);
+            }
+            Offer::TuneGun => {
+                let gun = &mut player.guns[player.selected_gun];
+                if gun.cooldown() <= MIN_COOLDOWN {
+                    return false;
+                }
+                gun.cooldown.add(Source::Shop, Modifier::Multiply(0.8));
+            }
+        }
+        player.coins -= cost;
+        self.offers[index] = None;
+        true
+    }
+}
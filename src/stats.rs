@@ -0,0 +1,66 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Source {
+    Floor,
+    Level,
+    Shop,
+    Item,
+    Status,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Modifier {
+    Add(f32),
+    Multiply(f32),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ModifierId(usize);
+
+#[derive(Clone, Debug)]
+pub struct Stat {
+    base: f32,
+    modifiers: Vec<(Source, ModifierId, Modifier)>,
+    next_id: usize,
+}
+impl Stat {
+    pub fn new(base: f32) -> Self {
+        Self {
+            base,
+            modifiers: Vec::new(),
+            next_id: 0,
+        }
+    }
+    pub fn add(&mut self, source: Source, modifier: Modifier) -> ModifierId {
+        let id = ModifierId(self.next_id);
+        self.next_id += 1;
+        let index = self
+            .modifiers
+            .iter()
+            .position(|(v, _, _)| *v > source)
+            .unwrap_or(self.modifiers.len());
+        self.modifiers.insert(index, (source, id, modifier));
+        id
+    }
+    pub fn remove(&mut self, source: Source) {
+        self.modifiers.retain(|(v, _, _)| *v != source);
+    }
+    pub fn remove_modifier(&mut self, id: ModifierId) {
+        self.modifiers.retain(|(_, v, _)| *v != id);
+    }
+    pub fn value(&self) -> f32 {
+        let added: f32 = self
+            .modifiers
+            .iter()
+            .map(|(_, _, v)| match v {
+                Modifier::Add(amount) => *amount,
+                Modifier::Multiply(_) => 0.,
+            })
+            .sum();
+        self.modifiers
+            .iter()
+            .fold(self.base + added, |value, (_, _, v)| match v {
+                Modifier::Add(_) => value,
+                Modifier::Multiply(factor) => value * factor,
+            })
+    }
+}
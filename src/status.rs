@@ -0,0 +1,124 @@
+const POISON_INTERVAL: usize = 30;
+const BURN_INTERVAL: usize = 20;
+const BURN_DAMAGE: isize = 2;
+const SLOW_FACTOR: f32 = 0.5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StatusKind {
+    Poison,
+    Slow,
+    Burn,
+    Stun,
+    Corrupt,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stacking {
+    Refresh,
+    Intensify(usize),
+    Ignore,
+}
+
+impl StatusKind {
+    pub const ALL: [StatusKind; 5] = [
+        StatusKind::Poison,
+        StatusKind::Slow,
+        StatusKind::Burn,
+        StatusKind::Stun,
+        StatusKind::Corrupt,
+    ];
+    pub fn name(self) -> &'static str {
+        match self {
+            StatusKind::Poison => "poison",
+            StatusKind::Slow => "slow",
+            StatusKind::Burn => "burn",
+            StatusKind::Stun => "stun",
+            StatusKind::Corrupt => "corrupt",
+        }
+    }
+    pub fn stacking(self) -> Stacking {
+        match self {
+            StatusKind::Poison => Stacking::Intensify(5),
+            StatusKind::Corrupt => Stacking::Intensify(3),
+            StatusKind::Slow | StatusKind::Burn => Stacking::Refresh,
+            StatusKind::Stun => Stacking::Ignore,
+        }
+    }
+    pub fn default_duration(self) -> usize {
+        match self {
+            StatusKind::Poison => 180,
+            StatusKind::Slow => 120,
+            StatusKind::Burn => 90,
+            StatusKind::Stun => 30,
+            StatusKind::Corrupt => 240,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Status {
+    pub kind: StatusKind,
+    pub remaining: usize,
+    pub stacks: usize,
+    age: usize,
+}
+impl Status {
+    fn tick_damage(&self) -> isize {
+        match self.kind {
+            StatusKind::Poison if self.age.is_multiple_of(POISON_INTERVAL) => self.stacks as isize,
+            StatusKind::Burn if self.age.is_multiple_of(BURN_INTERVAL) => BURN_DAMAGE,
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct StatusEffects {
+    pub active: Vec<Status>,
+}
+impl StatusEffects {
+    pub fn apply(&mut self, kind: StatusKind, duration: usize) {
+        if let Some(status) = self.active.iter_mut().find(|v| v.kind == kind) {
+            match kind.stacking() {
+                Stacking::Ignore => {}
+                Stacking::Refresh => status.remaining = status.remaining.max(duration),
+                Stacking::Intensify(max) => {
+                    status.remaining = status.remaining.max(duration);
+                    status.stacks = (status.stacks + 1).min(max);
+                }
+            }
+            return;
+        }
+        self.active.push(Status {
+            kind,
+            remaining: duration,
+            stacks: 1,
+            age: 0,
+        });
+    }
+    pub fn update(&mut self) -> isize {
+        let mut damage = 0;
+        for status in &mut self.active {
+            status.age += 1;
+            status.remaining = status.remaining.saturating_sub(1);
+            damage += status.tick_damage();
+        }
+        self.active.retain(|v| v.remaining > 0);
+        damage
+    }
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.active.iter().any(|v| v.kind == kind)
+    }
+    pub fn speed_factor(&self) -> f32 {
+        if self.has(StatusKind::Stun) {
+            0.
+        } else if self.has(StatusKind::Slow) {
+            SLOW_FACTOR
+        } else {
+            1.
+        }
+    }
+    pub fn clear(&mut self) {
+        self.active.clear();
+    }
+}
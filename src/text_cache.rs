@@ -0,0 +1,49 @@
+use quicksilver::graphics::{Font, FontStyle, Image};
+use quicksilver::Result;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+pub struct TextCache {
+    capacity: usize,
+    entries: RefCell<HashMap<String, (Image, u64)>>,
+    clock: Cell<u64>,
+    scale: Cell<f32>,
+}
+impl TextCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: RefCell::new(HashMap::new()),
+            clock: Cell::new(0),
+            scale: Cell::new(0.),
+        }
+    }
+    /// Drops every cached image when the font scale, and so the rendered size, changes.
+    pub fn set_scale(&self, scale: f32) {
+        if self.scale.get() != scale {
+            self.scale.set(scale);
+            self.entries.borrow_mut().clear();
+        }
+    }
+    pub fn get(&self, font: &Font, style: &FontStyle, text: &str) -> Result<Image> {
+        let tick = self.clock.get() + 1;
+        self.clock.set(tick);
+        let mut entries = self.entries.borrow_mut();
+        if let Some(entry) = entries.get_mut(text) {
+            entry.1 = tick;
+            return Ok(entry.0.clone());
+        }
+        let image = font.render(text, style)?;
+        if entries.len() >= self.capacity {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(text.to_string(), (image.clone(), tick));
+        Ok(image)
+    }
+}
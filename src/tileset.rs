@@ -0,0 +1,72 @@
+use crate::grid::Dir;
+use crate::gun::ShapeChoise;
+use crate::polarity::Polarity;
+use quicksilver::geom::Rectangle;
+use quicksilver::graphics::Image;
+use quicksilver::Result;
+use std::collections::HashMap;
+
+pub struct Tileset {
+    sprites: HashMap<String, Image>,
+}
+impl Tileset {
+    pub fn load() -> Result<Self> {
+        let atlas = Image::from_bytes(include_bytes!("../static/tileset.png"))?;
+        let manifest = include_str!("../static/tileset.txt");
+        Ok(Self::from_manifest(&atlas, manifest))
+    }
+    pub fn from_manifest(atlas: &Image, manifest: &str) -> Self {
+        let mut sprites = HashMap::new();
+        for line in manifest.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.splitn(2, '=');
+            let (name, region) = match (parts.next(), parts.next()) {
+                (Some(name), Some(region)) => (name.trim(), region),
+                _ => continue,
+            };
+            let numbers: Vec<f32> = region
+                .split(',')
+                .filter_map(|v| v.trim().parse().ok())
+                .collect();
+            if let [x, y, width, height] = numbers[..] {
+                let region = Rectangle::new((x, y), (width, height));
+                sprites.insert(name.to_string(), atlas.subimage(region));
+            }
+        }
+        Self { sprites }
+    }
+    pub fn get(&self, name: &str) -> Option<&Image> {
+        self.sprites.get(name)
+    }
+}
+
+pub fn wall_name(open_sides: u8) -> String {
+    format!("wall_{}", open_sides)
+}
+
+pub fn player_prefix(facing: Dir) -> &'static str {
+    match facing {
+        Dir::Up => "player_up",
+        Dir::Right => "player_right",
+        Dir::Down => "player_down",
+        Dir::Left => "player_left",
+    }
+}
+
+pub fn monster_prefix(polarity: Polarity) -> &'static str {
+    match polarity {
+        Polarity::Positive => "monster_positive",
+        Polarity::Negative => "monster_negative",
+    }
+}
+
+pub fn bullet_name(shape: ShapeChoise) -> &'static str {
+    match shape {
+        ShapeChoise::Rectangle => "bullet_rectangle",
+        ShapeChoise::Circle => "bullet_circle",
+        ShapeChoise::Triangle => "bullet_triangle",
+    }
+}